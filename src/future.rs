@@ -2,11 +2,28 @@
 
 //! Tools for futures
 
-use std::{
+use core::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
+use either::{Either, Left, Right};
+use futures::TryFuture;
+use pin_project::pin_project;
+
+pub use inspect::Inspect;
+#[cfg(feature = "alloc")]
+pub use join_all_ok::JoinAllOk;
+pub use map_err::MapErr;
+pub use map_ok::MapOk;
+pub use tap_err::TapErr;
+
+mod inspect;
+#[cfg(feature = "alloc")]
+mod join_all_ok;
+mod map_err;
+mod map_ok;
+mod tap_err;
 
 /// Returns a `Future` that returns `Pending` the first time it is polled and `Ready` afterwards.
 pub fn yield_now() -> YieldNow {
@@ -17,6 +34,18 @@ pub fn yield_now() -> YieldNow {
 #[derive(Debug)]
 pub struct YieldNow(bool);
 
+impl YieldNow {
+    /// Makes this future return `Pending` again the next time it is polled.
+    pub fn reset(&mut self) {
+        self.0 = false;
+    }
+
+    /// Returns whether this future has already yielded and will be ready when polled.
+    pub fn is_ready(&self) -> bool {
+        self.0
+    }
+}
+
 impl Future for YieldNow {
     type Output = ();
 
@@ -31,11 +60,416 @@ impl Future for YieldNow {
     }
 }
 
+/// Returns a cloneable `Future` that returns `Pending` the first time it is polled and `Ready`
+/// afterwards.
+///
+/// This behaves like [`yield_now`], but the returned future can be cloned. Each clone has its own
+/// state and yields once on its first poll, even if the original future has already yielded.
+pub fn shared_yield() -> SharedYield {
+    SharedYield(yield_now())
+}
+
+/// Future returned by [`shared_yield`]
+#[derive(Debug)]
+pub struct SharedYield(YieldNow);
+
+impl Clone for SharedYield {
+    fn clone(&self) -> Self {
+        shared_yield()
+    }
+}
+
+impl Future for SharedYield {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.0).poll(ctx)
+    }
+}
+
+/// Budget that [`cooperate`] resets to once exhausted
+pub const COOPERATE_BUDGET: u32 = 128;
+
+/// Returns a `Future` that consumes one unit of `budget` and yields if the budget is exhausted
+///
+/// Each poll decrements `budget`. When it reaches 0, `budget` is reset to [`COOPERATE_BUDGET`]
+/// and the future behaves like [`yield_now`]; otherwise it is immediately ready. Awaiting this
+/// future in a loop only yields to the executor once every `COOPERATE_BUDGET` iterations.
+pub fn cooperate(budget: &mut u32) -> Cooperate<'_> {
+    Cooperate(budget)
+}
+
+/// Future returned by [`cooperate`]
+#[derive(Debug)]
+pub struct Cooperate<'a>(&'a mut u32);
+
+impl Future for Cooperate<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<()> {
+        *self.0 = self.0.saturating_sub(1);
+        if *self.0 == 0 {
+            *self.0 = COOPERATE_BUDGET;
+            ctx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
+/// Returns a `Future` that returns `Pending` the first `n` times it is polled and `Ready`
+/// afterwards.
+///
+/// `yield_n(1)` behaves like [`yield_now`] and `yield_n(0)` is immediately ready.
+pub fn yield_n(n: usize) -> YieldN {
+    YieldN(n)
+}
+
+/// Future returned by [`yield_n`]
+#[derive(Debug)]
+pub struct YieldN(usize);
+
+impl Future for YieldN {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            Poll::Ready(())
+        } else {
+            self.0 -= 1;
+            ctx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Polls `fut` up to `n` times with a no-op waker
+///
+/// Returns `Ready` with the output of `fut` if it completes within `n` polls, or `Pending`
+/// otherwise, in which case `fut` is dropped. This drives futures deterministically, e.g. in tests.
+#[cfg(any(feature = "test-util", test))]
+pub fn poll_n_times<Fut: Future>(fut: Fut, n: usize) -> Poll<Fut::Output> {
+    let mut fut = core::pin::pin!(fut);
+    let mut ctx = Context::from_waker(futures::task::noop_waker_ref());
+    (0..n)
+        .map(|_| fut.as_mut().poll(&mut ctx))
+        .find(Poll::is_ready)
+        .unwrap_or(Poll::Pending)
+}
+
+/// Returns a `Future` that resolves to `value` the first time it is polled.
+///
+/// This is the counterpart of [`yield_now`] for futures that are immediately ready.
+pub fn now<T>(value: T) -> Now<T> {
+    Now(Some(value))
+}
+
+/// Future returned by [`now`]
+#[derive(Debug)]
+pub struct Now<T>(Option<T>);
+
+impl<T> Unpin for Now<T> {}
+
+impl<T> Future for Now<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<T> {
+        Poll::Ready(self.0.take().expect("Now polled after completion"))
+    }
+}
+
+/// Returns a `Future` that polls `fut` once and resolves to its output if it is ready, or `None`
+/// otherwise.
+///
+/// The returned future is always ready and never waits for `fut` to make progress.
+pub fn poll_immediate<Fut: Future>(fut: Fut) -> PollImmediate<Fut> {
+    PollImmediate(fut)
+}
+
+/// Future returned by [`poll_immediate`]
+#[pin_project]
+#[derive(Debug)]
+pub struct PollImmediate<Fut>(#[pin] Fut);
+
+impl<Fut: Future> Future for PollImmediate<Fut> {
+    type Output = Option<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().0.poll(ctx) {
+            Poll::Ready(output) => Poll::Ready(Some(output)),
+            Poll::Pending => Poll::Ready(None),
+        }
+    }
+}
+
+/// Returns a `Future` that polls `fut` and yields once if it is not ready
+///
+/// If `fut` is not ready the first time it is polled, the returned future wakes the current task
+/// and returns `Pending`, giving other tasks a chance to run. Subsequent polls are forwarded to
+/// `fut`, so if `fut` is ready by then, it completes after a single extra scheduler turn.
+pub fn ready_or_yield<Fut: Future>(fut: Fut) -> ReadyOrYield<Fut> {
+    ReadyOrYield {
+        fut,
+        state: ReadyOrYieldState::Start,
+    }
+}
+
+/// Future returned by [`ready_or_yield`]
+#[pin_project]
+#[derive(Debug)]
+pub struct ReadyOrYield<Fut> {
+    #[pin]
+    fut: Fut,
+    state: ReadyOrYieldState,
+}
+
+#[derive(Debug)]
+enum ReadyOrYieldState {
+    Start,
+    Yielded,
+}
+
+impl<Fut: Future> Future for ReadyOrYield<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match (this.fut.poll(ctx), &this.state) {
+            (Poll::Ready(output), _) => Poll::Ready(output),
+            (Poll::Pending, ReadyOrYieldState::Start) => {
+                *this.state = ReadyOrYieldState::Yielded;
+                ctx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            (Poll::Pending, ReadyOrYieldState::Yielded) => Poll::Pending,
+        }
+    }
+}
+
+/// Returns a `Future` that polls `fut` and yields after every `polls_per_yield` consecutive
+/// `Pending` results
+///
+/// Once `fut` returned `Pending` `polls_per_yield` times in a row, the next poll of the returned
+/// future does not poll `fut` but wakes the current task and returns `Pending`, giving other tasks
+/// a chance to run. This keeps a future that keeps waking itself from monopolizing the executor.
+/// A `polls_per_yield` of 0 is treated as 1.
+pub fn with_yield_budget<Fut: Future>(fut: Fut, polls_per_yield: u32) -> WithYieldBudget<Fut> {
+    WithYieldBudget {
+        fut,
+        polls_per_yield: polls_per_yield.max(1),
+        pending: 0,
+    }
+}
+
+/// Future returned by [`with_yield_budget`]
+#[pin_project]
+#[derive(Debug)]
+pub struct WithYieldBudget<Fut> {
+    #[pin]
+    fut: Fut,
+    polls_per_yield: u32,
+    pending: u32,
+}
+
+impl<Fut: Future> Future for WithYieldBudget<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if *this.pending >= *this.polls_per_yield {
+            *this.pending = 0;
+            ctx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        match this.fut.poll(ctx) {
+            Poll::Ready(output) => Poll::Ready(output),
+            Poll::Pending => {
+                *this.pending += 1;
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Returns a `Future` that resolves to the output of whichever of `a` and `b` completes first
+///
+/// Both futures are polled each time the returned future is polled, `a` first, so `a` wins if
+/// both are ready in the same poll. Unlike [`select`](futures::future::select), the future that
+/// did not complete is not returned and is dropped with the returned future.
+pub fn race2<A, B>(a: A, b: B) -> Race2<A, B>
+where
+    A: Future,
+    B: Future,
+{
+    Race2 { a, b }
+}
+
+/// Future returned by [`race2`]
+#[pin_project]
+#[derive(Debug)]
+pub struct Race2<A, B> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+}
+
+impl<A, B> Future for Race2<A, B>
+where
+    A: Future,
+    B: Future,
+{
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if let Poll::Ready(x) = this.a.poll(ctx) {
+            return Poll::Ready(Left(x));
+        }
+        this.b.poll(ctx).map(Right)
+    }
+}
+
+/// Returns a `Future` that resolves to the first success of `a` and `b`, or to the last error if
+/// both fail
+///
+/// Both futures are polled each time the returned future is polled, `a` first, so `a` wins if
+/// both succeed in the same poll. A future that failed is not polled again. This is like
+/// [`select_ok`](https://docs.rs/futures/latest/futures/future/fn.select_ok.html) for two futures,
+/// without allocating.
+pub fn select_ok2<A, B, T, E>(a: A, b: B) -> SelectOk2<A, B>
+where
+    A: Future<Output = Result<T, E>>,
+    B: Future<Output = Result<T, E>>,
+{
+    SelectOk2 {
+        a,
+        b,
+        a_failed: false,
+        b_failed: false,
+    }
+}
+
+/// Future returned by [`select_ok2`]
+#[pin_project]
+#[derive(Debug)]
+pub struct SelectOk2<A, B> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    a_failed: bool,
+    b_failed: bool,
+}
+
+impl<A, B, T, E> Future for SelectOk2<A, B>
+where
+    A: Future<Output = Result<T, E>>,
+    B: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if !*this.a_failed {
+            match this.a.poll(ctx) {
+                Poll::Ready(Ok(x)) => return Poll::Ready(Ok(x)),
+                Poll::Ready(Err(e)) if *this.b_failed => return Poll::Ready(Err(e)),
+                Poll::Ready(Err(_)) => *this.a_failed = true,
+                Poll::Pending => {}
+            }
+        }
+        if !*this.b_failed {
+            match this.b.poll(ctx) {
+                Poll::Ready(Ok(x)) => return Poll::Ready(Ok(x)),
+                Poll::Ready(Err(e)) if *this.a_failed => return Poll::Ready(Err(e)),
+                Poll::Ready(Err(_)) => *this.b_failed = true,
+                Poll::Pending => {}
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// Returns a `Future` that calls `f` with a reference to the output of `fut` before returning it
+pub fn inspect<Fut, F>(fut: Fut, f: F) -> Inspect<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(&Fut::Output),
+{
+    Inspect::new(fut, f)
+}
+
+/// Returns a `Future` that resolves to the `Ok` values of all `futures`, or to the first error
+///
+/// The values are returned in the order of `futures`. When one of the futures fails, the other
+/// futures are dropped.
+#[cfg(feature = "alloc")]
+pub fn join_all_ok<I>(futures: I) -> JoinAllOk<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture,
+{
+    JoinAllOk::new(futures)
+}
+
+/// Returns a `Future` that transforms the `Ok` value of `fut` with `f`
+///
+/// Errors are passed through untouched.
+pub fn map_ok<Fut, F, T2>(fut: Fut, f: F) -> MapOk<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Ok) -> T2,
+{
+    MapOk::new(fut, f)
+}
+
+/// Returns a `Future` that transforms the `Err` value of `fut` with `f`
+///
+/// `Ok` values are passed through untouched.
+pub fn map_err<Fut, F, E2>(fut: Fut, f: F) -> MapErr<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Error) -> E2,
+{
+    MapErr::new(fut, f)
+}
+
+/// Returns a `Future` that calls `f` with a reference to the error of `fut`, if any, before
+/// returning it
+pub fn tap_err<Fut, F, T, E>(fut: Fut, f: F) -> TapErr<Fut, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnOnce(&E),
+{
+    TapErr::new(fut, f)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::future::yield_now;
-    use futures::{executor::block_on, FutureExt};
-    use std::future::ready;
+    use crate::future::{
+        cooperate, now, poll_immediate, poll_n_times, race2, ready_or_yield, select_ok2,
+        shared_yield, with_yield_budget, yield_n, yield_now, COOPERATE_BUDGET,
+    };
+    use either::{Left, Right};
+    use futures::{executor::block_on, future::poll_fn, task::noop_waker_ref, FutureExt};
+    use std::{
+        cell::Cell,
+        future::{ready, Future},
+        pin::{pin, Pin},
+        task::Context,
+    };
+
+    fn count_pending<F: Future>(fut: F) -> usize {
+        let mut fut = pin!(fut);
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        let mut n = 0;
+        while fut.as_mut().poll(&mut ctx).is_pending() {
+            n += 1;
+        }
+        n
+    }
 
     #[test]
     fn it_works() {
@@ -52,4 +486,104 @@ mod tests {
             1,
         );
     }
+
+    #[test]
+    fn now_is_ready_on_first_poll() {
+        assert_eq!(count_pending(now(1)), 0);
+        assert_eq!(
+            block_on(futures::future::select(yield_now().map(|_| 2), now(1)))
+                .factor_first()
+                .0,
+            1,
+        );
+    }
+
+    #[test]
+    fn yield_now_yields_again_after_reset() {
+        let mut fut = yield_now();
+        assert!(!fut.is_ready());
+        assert_eq!(count_pending(&mut fut), 1);
+        assert!(fut.is_ready());
+        fut.reset();
+        assert!(!fut.is_ready());
+        assert_eq!(count_pending(&mut fut), 1);
+    }
+
+    #[test]
+    fn yield_n_returns_pending_n_times() {
+        assert_eq!(count_pending(yield_n(0)), 0);
+        assert_eq!(count_pending(yield_n(1)), count_pending(yield_now()));
+        assert_eq!(count_pending(yield_n(3)), 3);
+    }
+
+    #[test]
+    fn poll_immediate_does_not_wait() {
+        let mut fut = yield_now();
+        assert_eq!(block_on(poll_immediate(&mut fut)), None);
+        assert_eq!(block_on(poll_immediate(&mut fut)), Some(()));
+    }
+
+    #[test]
+    fn ready_or_yield_completes_after_one_yield() {
+        assert_eq!(count_pending(ready_or_yield(ready(()))), 0);
+        assert_eq!(count_pending(ready_or_yield(yield_now())), 1);
+        assert_eq!(block_on(ready_or_yield(yield_n(1).map(|_| 3))), 3);
+    }
+
+    #[test]
+    fn with_yield_budget_yields_periodically() {
+        let inner_polls = Cell::new(0);
+        let mut busy = yield_n(10);
+        let busy = poll_fn(|ctx| {
+            inner_polls.set(inner_polls.get() + 1);
+            Pin::new(&mut busy).poll(ctx)
+        });
+        assert_eq!(count_pending(with_yield_budget(busy, 3)), 13);
+        assert_eq!(inner_polls.get(), 11);
+    }
+
+    #[test]
+    fn cooperate_yields_when_budget_is_exhausted() {
+        let mut budget = 3;
+        assert_eq!(count_pending(cooperate(&mut budget)), 0);
+        assert_eq!(count_pending(cooperate(&mut budget)), 0);
+        assert_eq!(budget, 1);
+        assert_eq!(count_pending(cooperate(&mut budget)), 1);
+        assert_eq!(budget, COOPERATE_BUDGET - 1);
+        assert_eq!(count_pending(cooperate(&mut budget)), 0);
+    }
+
+    #[test]
+    fn race2_resolves_to_first_completed_future() {
+        assert_eq!(block_on(race2(yield_now(), ready(1))), Right(1));
+        assert_eq!(block_on(race2(ready(1), yield_now())), Left(1));
+        assert_eq!(block_on(race2(ready(1), ready(2))), Left(1));
+    }
+
+    #[test]
+    fn select_ok2_resolves_to_first_success() {
+        let failed = ready(Err::<u32, _>("a"));
+        let succeeded = yield_now().map(|_| Ok(2));
+        assert_eq!(block_on(select_ok2(failed, succeeded)), Ok(2));
+        let failed_later = yield_now().map(|_| Err("b"));
+        assert_eq!(
+            block_on(select_ok2(ready(Err("a")), failed_later)),
+            Err::<u32, _>("b")
+        );
+    }
+
+    #[test]
+    fn poll_n_times_stops_after_n_polls() {
+        assert!(poll_n_times(yield_n(3), 2).is_pending());
+        assert!(poll_n_times(yield_n(3), 4).is_ready());
+    }
+
+    #[test]
+    fn shared_yield_clones_yield_once_each() {
+        let first = shared_yield();
+        let second = first.clone();
+        assert_eq!(count_pending(first), 1);
+        assert_eq!(count_pending(second.clone()), 1);
+        assert_eq!(count_pending(second), 1);
+    }
 }
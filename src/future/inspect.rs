@@ -0,0 +1,72 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use pin_project::pin_project;
+
+/// Future returned by [`inspect`](crate::future::inspect).
+#[pin_project]
+pub struct Inspect<Fut, F> {
+    #[pin]
+    fut: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F> Inspect<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(&Fut::Output),
+{
+    pub(crate) fn new(fut: Fut, f: F) -> Self {
+        Self { fut, f: Some(f) }
+    }
+}
+
+impl<Fut: Debug, F> Debug for Inspect<Fut, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Inspect")
+            .field("fut", &self.fut)
+            .field("done", &self.f.is_none())
+            .finish()
+    }
+}
+
+impl<Fut, F> Future for Inspect<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(&Fut::Output),
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.fut.poll(ctx));
+        let f = this.f.take().expect("Inspect polled after completion");
+        f(&output);
+        Poll::Ready(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::{inspect, yield_now};
+    use futures::executor::block_on;
+
+    #[test]
+    fn closure_observes_output() {
+        let mut seen = Vec::new();
+        let r = block_on(inspect(
+            async {
+                yield_now().await;
+                3
+            },
+            |&x| seen.push(x),
+        ));
+        assert_eq!(r, 3);
+        assert_eq!(seen, [3]);
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::TryFuture;
+
+/// Future returned by [`join_all_ok`](crate::future::join_all_ok).
+pub struct JoinAllOk<Fut>
+where
+    Fut: TryFuture,
+{
+    elems: Vec<Elem<Fut>>,
+}
+
+impl<Fut> JoinAllOk<Fut>
+where
+    Fut: TryFuture,
+{
+    pub(crate) fn new<I>(futures: I) -> Self
+    where
+        I: IntoIterator<Item = Fut>,
+    {
+        Self {
+            elems: futures
+                .into_iter()
+                .map(|fut| Elem::Pending(Box::pin(fut)))
+                .collect(),
+        }
+    }
+}
+
+impl<Fut> Debug for JoinAllOk<Fut>
+where
+    Fut: TryFuture,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pending = self
+            .elems
+            .iter()
+            .filter(|elem| matches!(elem, Elem::Pending(_)))
+            .count();
+        f.debug_struct("JoinAllOk")
+            .field("len", &self.elems.len())
+            .field("pending", &pending)
+            .finish()
+    }
+}
+
+impl<Fut> Unpin for JoinAllOk<Fut> where Fut: TryFuture {}
+
+impl<Fut> Future for JoinAllOk<Fut>
+where
+    Fut: TryFuture,
+{
+    type Output = Result<Vec<Fut::Ok>, Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut done = true;
+        for elem in &mut self.elems {
+            let Elem::Pending(fut) = elem else {
+                continue;
+            };
+            match fut.as_mut().try_poll(ctx) {
+                Poll::Ready(Ok(x)) => *elem = Elem::Done(x),
+                Poll::Ready(Err(e)) => {
+                    self.elems.clear();
+                    return Poll::Ready(Err(e));
+                }
+                Poll::Pending => done = false,
+            }
+        }
+        if !done {
+            return Poll::Pending;
+        }
+        let outputs = mem::take(&mut self.elems)
+            .into_iter()
+            .map(|elem| match elem {
+                Elem::Done(x) => x,
+                Elem::Pending(_) => unreachable!("all futures completed"),
+            })
+            .collect();
+        Poll::Ready(Ok(outputs))
+    }
+}
+
+enum Elem<Fut>
+where
+    Fut: TryFuture,
+{
+    Pending(Pin<Box<Fut>>),
+    Done(Fut::Ok),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::{join_all_ok, yield_n};
+    use futures::{executor::block_on, FutureExt};
+
+    #[test]
+    fn all_values_are_returned_in_order() {
+        let futs = (0..3).map(|i| yield_n(3 - i).map(move |_| Ok::<_, ()>(i)));
+        assert_eq!(block_on(join_all_ok(futs)), Ok(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn first_error_is_returned() {
+        let futs = (0..4).map(|i| {
+            yield_n(i).map(move |_| match i {
+                1 => Err(i),
+                2 => panic!("future polled after an error"),
+                _ => Ok(i),
+            })
+        });
+        assert_eq!(block_on(join_all_ok(futs)), Err(1));
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::TryFuture;
+use pin_project::pin_project;
+
+/// Future returned by [`map_err`](crate::future::map_err).
+#[pin_project]
+pub struct MapErr<Fut, F> {
+    #[pin]
+    fut: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, T> MapErr<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Error) -> T,
+{
+    pub(crate) fn new(fut: Fut, f: F) -> Self {
+        Self { fut, f: Some(f) }
+    }
+}
+
+impl<Fut: Debug, F> Debug for MapErr<Fut, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapErr")
+            .field("fut", &self.fut)
+            .field("done", &self.f.is_none())
+            .finish()
+    }
+}
+
+impl<Fut, F, T> Future for MapErr<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Error) -> T,
+{
+    type Output = Result<Fut::Ok, T>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = ready!(this.fut.try_poll(ctx));
+        let f = this.f.take().expect("MapErr polled after completion");
+        Poll::Ready(res.map_err(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::map_err;
+    use futures::executor::block_on;
+    use std::future::ready;
+
+    #[test]
+    fn err_is_mapped() {
+        let r = block_on(map_err(ready(Err::<(), _>("boom")), |e| e.len()));
+        assert_eq!(r, Err(4));
+    }
+
+    #[test]
+    fn ok_is_untouched() {
+        let r = block_on(map_err(ready(Ok::<_, &str>(1)), |e| e.len()));
+        assert_eq!(r, Ok(1));
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::TryFuture;
+use pin_project::pin_project;
+
+/// Future returned by [`map_ok`](crate::future::map_ok).
+#[pin_project]
+pub struct MapOk<Fut, F> {
+    #[pin]
+    fut: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, T> MapOk<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Ok) -> T,
+{
+    pub(crate) fn new(fut: Fut, f: F) -> Self {
+        Self { fut, f: Some(f) }
+    }
+}
+
+impl<Fut: Debug, F> Debug for MapOk<Fut, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapOk")
+            .field("fut", &self.fut)
+            .field("done", &self.f.is_none())
+            .finish()
+    }
+}
+
+impl<Fut, F, T> Future for MapOk<Fut, F>
+where
+    Fut: TryFuture,
+    F: FnOnce(Fut::Ok) -> T,
+{
+    type Output = Result<T, Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = ready!(this.fut.try_poll(ctx));
+        let f = this.f.take().expect("MapOk polled after completion");
+        Poll::Ready(res.map(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::map_ok;
+    use futures::executor::block_on;
+    use std::future::ready;
+
+    #[test]
+    fn ok_is_mapped() {
+        let r = block_on(map_ok(ready(Ok::<_, ()>(1)), |x| x + 1));
+        assert_eq!(r, Ok(2));
+    }
+
+    #[test]
+    fn err_is_untouched() {
+        let r = block_on(map_ok(ready(Err::<i32, _>("boom")), |x| x + 1));
+        assert_eq!(r, Err("boom"));
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use pin_project::pin_project;
+
+/// Future returned by [`tap_err`](crate::future::tap_err).
+#[pin_project]
+pub struct TapErr<Fut, F> {
+    #[pin]
+    fut: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, T, E> TapErr<Fut, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnOnce(&E),
+{
+    pub(crate) fn new(fut: Fut, f: F) -> Self {
+        Self { fut, f: Some(f) }
+    }
+}
+
+impl<Fut: Debug, F> Debug for TapErr<Fut, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TapErr")
+            .field("fut", &self.fut)
+            .field("done", &self.f.is_none())
+            .finish()
+    }
+}
+
+impl<Fut, F, T, E> Future for TapErr<Fut, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnOnce(&E),
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.fut.poll(ctx));
+        let f = this.f.take().expect("TapErr polled after completion");
+        if let Err(e) = &output {
+            f(e);
+        }
+        Poll::Ready(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::tap_err;
+    use futures::executor::block_on;
+    use std::future::ready;
+
+    #[test]
+    fn closure_observes_error() {
+        let mut seen = Vec::new();
+        let r = block_on(tap_err(ready(Err::<(), _>("boom")), |&e| seen.push(e)));
+        assert_eq!(r, Err("boom"));
+        assert_eq!(seen, ["boom"]);
+    }
+
+    #[test]
+    fn closure_ignores_ok() {
+        let mut called = false;
+        let r = block_on(tap_err(ready(Ok::<_, &str>(1)), |_| called = true));
+        assert_eq!(r, Ok(1));
+        assert!(!called);
+    }
+}
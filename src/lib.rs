@@ -7,15 +7,29 @@
 //!
 //! Extensions to the [`futures`](https://docs.rs/futures) crate.
 //!
+//! # Features
+//! - `std` (default): Enables the combinators relying on the standard library, e.g. for
+//!   synchronization. Implies `alloc`.
+//! - `alloc`: Enables the combinators requiring allocations, e.g. to zip a variable number of
+//!   streams.
+//! - `test-util`: Enables helpers to drive futures deterministically in tests.
+//!
+//! Without these features, this crate is `no_std` and only depends on `core`.
+//!
 //! # Contribute
 //! All contributions shall be licensed under the [0BSD license](https://spdx.org/licenses/0BSD.html).
 
 #![deny(missing_docs)]
 #![deny(warnings)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub use sink::SinkTools;
 pub use stream::StreamTools;
 
 pub mod future;
+pub mod prelude;
 pub mod sink;
 pub mod stream;
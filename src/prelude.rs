@@ -0,0 +1,44 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+//! Common imports
+//!
+//! This module re-exports the extension traits and the most common free functions of this crate.
+//! It does not export any module, and the methods of the extension traits do not share names with
+//! those of the `futures` extension traits, so it can be glob-imported alongside
+//! [`futures::prelude`](https://docs.rs/futures/latest/futures/prelude/index.html). The only
+//! exception is for types that are both a [`Stream`](futures::Stream) and a
+//! [`Sink`](futures::Sink): [`SinkTools::filter`] and [`SinkTools::inspect`] then need to be called
+//! with the fully qualified syntax.
+//!
+//! ```
+//! use either::Either;
+//! use futures::{channel::mpsc, executor::block_on, prelude::*, stream};
+//! use futuristic::prelude::*;
+//!
+//! let zipped = stream::iter([1, 2]).zip_latest(stream::iter(['a', 'b']));
+//! assert_eq!(block_on(zipped.collect::<Vec<_>>()), [(1, 'a'), (2, 'b')]);
+//!
+//! let chunks = stream::iter(0..5).chunks_of(2);
+//! assert_eq!(block_on(chunks.collect::<Vec<_>>()), [vec![0, 1], vec![2, 3], vec![4]]);
+//!
+//! let (evens, even_receiver) = mpsc::unbounded();
+//! let (odds, odd_receiver) = mpsc::unbounded();
+//! let mut sink = evens.fork(odds, |n: i32| {
+//!     if n % 2 == 0 {
+//!         Either::Left(n)
+//!     } else {
+//!         Either::Right(n)
+//!     }
+//! });
+//! block_on(async {
+//!     sink.send_all(&mut stream::iter(0..5).map(Ok)).await.unwrap();
+//!     sink.close().await.unwrap();
+//! });
+//! assert_eq!(block_on(even_receiver.collect::<Vec<_>>()), [0, 2, 4]);
+//! assert_eq!(block_on(odd_receiver.collect::<Vec<_>>()), [1, 3]);
+//! ```
+
+pub use crate::future::yield_now;
+#[cfg(feature = "alloc")]
+pub use crate::stream::{zip_latest_all, zip_latest_with_all};
+pub use crate::{SinkTools, StreamTools};
@@ -2,12 +2,89 @@
 
 //! Tools for sinks
 
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::AtomicUsize;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
 use either::Either;
-use futures::Sink;
+use futures::{Sink, SinkExt, Stream};
 
-pub use fork::Fork;
+#[cfg(feature = "std")]
+pub use buffer::{BufferSink, DrainedBelow};
+#[cfg(feature = "alloc")]
+pub use capacity::CapacitySink;
+#[cfg(feature = "alloc")]
+pub use chunked::Chunked;
+pub use close_after::{CloseAfter, CloseAfterError, Excess};
+#[cfg(feature = "alloc")]
+pub use counting::CountingSink;
+pub use dedup::DedupSink;
+pub use drain::Drain;
+pub use drained_close::DrainedClose;
+pub use filter::FilterSink;
+pub use flush_every::FlushEvery;
+pub use fork::{
+    Broadcast, Choice3, FallibleFork, Fork, Fork3, ForkBroadcast, ForkBuffered, ForkError,
+    ForkWithErr, OrderedFork, SplitEither,
+};
+pub use forward_from::ForwardFrom;
+pub use from_fn::FromFn;
+pub use inspect::InspectSink;
+pub use map_item::MapItem;
+pub use on_error::OnError;
+#[cfg(feature = "std")]
+pub use ready_signal::{ReadySignal, ReadySignals};
+pub use retry::Retry;
+#[cfg(feature = "alloc")]
+pub use scatter_weighted::ScatterWeighted;
+#[cfg(feature = "std")]
+pub use split_by_key::SplitByKey;
+pub use timeout_close_via::{TimeoutCloseError, TimeoutCloseVia};
+pub use unzip::Unzip;
+#[cfg(feature = "alloc")]
+pub use with_fan::WithFan;
+pub use with_filter_map::WithFilterMap;
+pub use zip_sinks::ZipSinks;
 
+#[cfg(feature = "std")]
+mod buffer;
+#[cfg(feature = "alloc")]
+mod capacity;
+#[cfg(feature = "alloc")]
+mod chunked;
+mod close_after;
+#[cfg(feature = "alloc")]
+mod counting;
+mod dedup;
+mod drain;
+mod drained_close;
+mod filter;
+mod flush_every;
 mod fork;
+mod forward_from;
+mod from_fn;
+mod inspect;
+mod map_item;
+mod on_error;
+#[cfg(feature = "std")]
+mod ready_signal;
+mod retry;
+#[cfg(feature = "alloc")]
+mod scatter_weighted;
+#[cfg(feature = "std")]
+mod split_by_key;
+mod timeout_close_via;
+mod unzip;
+#[cfg(feature = "alloc")]
+mod with_fan;
+mod with_filter_map;
+mod zip_sinks;
 
 /// Extension trait for [`Sink`](futures::Sink).
 pub trait SinkTools<T>: Sink<T> {
@@ -24,6 +101,446 @@ pub trait SinkTools<T>: Sink<T> {
     {
         Fork::new(self, other, switch)
     }
+
+    /// Returns a sink that dispatches to `self` or `other`, one item at a time.
+    ///
+    /// This behaves like [`fork`](SinkTools::fork) but each item is sent and flushed before the
+    /// returned sink accepts the next item, so items reach the underlying sinks in the order they
+    /// were sent to the returned sink. This trades throughput for ordering.
+    fn ordered_fork<V, O, F, U>(self, other: O, switch: F) -> OrderedFork<V, Self, O, F, T, U>
+    where
+        Self: Sized,
+        F: FnMut(V) -> Either<T, U>,
+        O: Sink<U, Error = Self::Error>,
+    {
+        OrderedFork::new(self, other, switch)
+    }
+
+    /// Returns a sink that dispatches to `self` or `other`, buffering one item for each.
+    ///
+    /// This behaves like [`fork`](SinkTools::fork) but an item waiting for one of the underlying
+    /// sinks does not prevent items from being sent to the other sink. The returned sink is only
+    /// not ready when an item targets a sink that has not accepted its previous item yet.
+    fn fork_buffered<V, O, F, U>(self, other: O, switch: F) -> ForkBuffered<V, Self, O, F, T, U>
+    where
+        Self: Sized,
+        F: FnMut(V) -> Either<T, U>,
+        O: Sink<U, Error = Self::Error>,
+    {
+        ForkBuffered::new(self, other, switch)
+    }
+
+    /// Returns a sink that dispatches to `self` or `other` and converts their errors to `E`.
+    ///
+    /// This behaves like [`fork`](SinkTools::fork) but allows the underlying sinks to have
+    /// different error types.
+    ///
+    /// This is convenient to [`forward`](futures::StreamExt::forward) a `TryStream` whose error
+    /// type is `E`: the first error yielded by the stream is returned as is by `forward`, and
+    /// errors from the underlying sinks are converted to `E`, so that both kinds of errors can
+    /// still be told apart. `forward` does not flush the sink when the stream fails, so the last
+    /// item may remain buffered in the returned sink until it is flushed or closed.
+    fn fork_with_err<E, V, O, F, U>(
+        self,
+        other: O,
+        switch: F,
+    ) -> ForkWithErr<V, Self, O, F, T, U, E>
+    where
+        Self: Sized,
+        Self::Error: Into<E>,
+        F: FnMut(V) -> Either<T, U>,
+        O: Sink<U>,
+        O::Error: Into<E>,
+    {
+        Fork::new(
+            self.sink_map_err(Into::into as fn(_) -> E),
+            other.sink_map_err(Into::into as fn(_) -> E),
+            switch,
+        )
+    }
+
+    /// Returns a sink that dispatches to `self` or `other` and tells which of them failed.
+    ///
+    /// This behaves like [`fork_with_err`](SinkTools::fork_with_err) with errors from `self`
+    /// wrapped in [`ForkError::Left`] and errors from `other` wrapped in [`ForkError::Right`].
+    fn fallible_fork<V, O, F, U>(self, other: O, switch: F) -> FallibleFork<V, Self, O, F, T, U>
+    where
+        Self: Sized,
+        F: FnMut(V) -> Either<T, U>,
+        O: Sink<U>,
+    {
+        Fork::new(
+            self.sink_map_err(ForkError::Left as fn(_) -> _),
+            other.sink_map_err(ForkError::Right as fn(_) -> _),
+            switch,
+        )
+    }
+
+    /// Returns a sink that dispatches to `self`, `second` or `third`.
+    ///
+    /// Every item sent to the returned sink is passed to `switch` and the returned value is sent
+    /// to one of the underlying sinks. `First` values are sent to `self`, `Second` values to
+    /// `second` and `Third` values to `third`.
+    fn fork3<V, O2, O3, F, U2, U3>(
+        self,
+        second: O2,
+        third: O3,
+        switch: F,
+    ) -> Fork3<V, Self, O2, O3, F, T, U2, U3>
+    where
+        Self: Sized,
+        F: FnMut(V) -> Choice3<T, U2, U3>,
+        O2: Sink<U2, Error = Self::Error>,
+        O3: Sink<U3, Error = Self::Error>,
+    {
+        Fork3::new(self, second, third, switch)
+    }
+
+    /// Returns a sink that dispatches to `self`, `other` or both.
+    ///
+    /// Every item sent to the returned sink is passed to `switch`. `JustLeft` values are sent to
+    /// `self`, `JustRight` values to `other`, and both values of `Both` are sent to their
+    /// respective sinks. The returned sink is ready once all values of the previous item have been
+    /// accepted.
+    fn fork_broadcast<V, O, F, U>(self, other: O, switch: F) -> ForkBroadcast<V, Self, O, F, T, U>
+    where
+        Self: Sized,
+        F: FnMut(V) -> Broadcast<T, U>,
+        O: Sink<U, Error = Self::Error>,
+    {
+        ForkBroadcast::new(self, other, switch)
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns a sink that buffers up to `capacity` items before sending them to `self`.
+    ///
+    /// The returned sink is ready as long as its buffer has room. Buffered items are sent to `self`
+    /// when the buffer is full or when the returned sink is flushed or closed. A `capacity` of 0 is
+    /// treated as 1.
+    fn buffer_items(self, capacity: usize) -> BufferSink<Self, T>
+    where
+        Self: Sized,
+    {
+        BufferSink::new(self, capacity)
+    }
+
+    /// Returns a sink that collects items into batches of `n` and sends each batch to `self`.
+    ///
+    /// The returned sink is ready while the current batch has room. A full batch is sent to `self`
+    /// the next time the returned sink is polled for readiness. A partial batch is sent when the
+    /// returned sink is flushed or closed. This is the sink counterpart of
+    /// [`StreamExt::chunks`](futures::StreamExt::chunks).
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunked<U>(self, n: usize) -> Chunked<Self, U>
+    where
+        Self: Sized + Sink<Vec<U>>,
+    {
+        Chunked::new(self, n)
+    }
+
+    /// Returns a sink that sends at most `n` items to `self` and closes it afterwards.
+    ///
+    /// `self` is closed once `n` items have been sent to it and the returned sink is polled again.
+    /// Items sent afterwards are handled according to `excess`.
+    fn close_after(self, n: usize, excess: Excess) -> CloseAfter<Self, T>
+    where
+        Self: Sized,
+    {
+        CloseAfter::new(self, n, excess)
+    }
+
+    /// Returns a sink that counts the items sent to `self`, along with the counter.
+    ///
+    /// Items are forwarded unchanged. The counter is incremented each time `self` accepts an item,
+    /// so it can be read from another task to observe throughput.
+    #[cfg(feature = "alloc")]
+    fn counting(self) -> (CountingSink<Self, T>, Arc<AtomicUsize>)
+    where
+        Self: Sized,
+    {
+        CountingSink::new(self)
+    }
+
+    /// Returns a sink that drops items equal to the previous item sent to `self`.
+    ///
+    /// Only consecutive duplicates are dropped; an item equal to an older item is sent.
+    fn dedup(self) -> DedupSink<Self, T>
+    where
+        Self: Sized,
+        T: Clone + PartialEq,
+    {
+        DedupSink::new(self)
+    }
+
+    /// Returns a sink that discards and counts items, and closes `self` when closed.
+    ///
+    /// Items never reach `self`. The returned value is also a future that closes the sink and
+    /// resolves to the number of items accepted.
+    fn drain(self) -> Drain<Self, T>
+    where
+        Self: Sized,
+    {
+        Drain::new(self)
+    }
+
+    /// Returns a sink that only sends to `self` the items for which `pred` returns `true`.
+    ///
+    /// Other items are dropped without reaching `self`.
+    fn filter<P>(self, pred: P) -> FilterSink<Self, P, T>
+    where
+        Self: Sized,
+        P: FnMut(&T) -> bool,
+    {
+        FilterSink::new(self, pred)
+    }
+
+    /// Returns a sink that flushes `self` after every `n` items sent to it.
+    ///
+    /// `self` is flushed before accepting an item once `n` items have been sent to it since the
+    /// last flush. Explicit flushes also reset the count. An `n` of 0 is treated as 1.
+    fn flush_every(self, n: usize) -> FlushEvery<Self, T>
+    where
+        Self: Sized,
+    {
+        FlushEvery::new(self, n)
+    }
+
+    /// Returns a future that sends all the items of `stream` to `self` and then closes `self`.
+    ///
+    /// This is the sink-oriented counterpart of
+    /// [`StreamExt::forward`](futures::StreamExt::forward) for infallible streams. The returned
+    /// future fails as soon as `self` does.
+    fn forward_from<St>(self, stream: St) -> ForwardFrom<Self, St, T>
+    where
+        Self: Sized,
+        St: Stream<Item = T>,
+    {
+        ForwardFrom::new(self, stream)
+    }
+
+    /// Returns a sink that calls `f` on each item before sending it to `self`.
+    ///
+    /// Items are forwarded unchanged. This is the sink counterpart of
+    /// [`StreamExt::inspect`](futures::StreamExt::inspect).
+    fn inspect<G>(self, f: G) -> InspectSink<Self, G, T>
+    where
+        Self: Sized,
+        G: FnMut(&T),
+    {
+        InspectSink::new(self, f)
+    }
+
+    /// Returns a sink that passes each item to `f` and sends the returned value to `self`.
+    ///
+    /// Unlike [`SinkExt::with`], `f` is synchronous. This is the sink counterpart of
+    /// [`StreamExt::map`](futures::StreamExt::map).
+    fn map_item<G, U>(self, f: G) -> MapItem<Self, G, U, T>
+    where
+        Self: Sized,
+        G: FnMut(U) -> T,
+    {
+        MapItem::new(self, f)
+    }
+
+    /// Returns a sink that calls `f` on each error of `self` before returning it.
+    ///
+    /// Errors from all poll methods and [`start_send`](Sink::start_send) are passed to `f` and
+    /// then propagated unchanged.
+    fn on_error<G>(self, f: G) -> OnError<Self, G, T>
+    where
+        Self: Sized,
+        G: FnMut(&Self::Error),
+    {
+        OnError::new(self, f)
+    }
+
+    /// Returns a sink that reports the readiness of `self`, along with the stream of reports.
+    ///
+    /// Each call to `poll_ready` yields `true` on the returned stream if `self` was ready, or
+    /// `false` if it was pending. Items are forwarded unchanged. Reports are buffered without
+    /// bound until read, and are discarded if the stream is dropped. The stream ends once the
+    /// sink is dropped and all reports have been read.
+    #[cfg(feature = "std")]
+    fn ready_signal(self) -> (ReadySignal<Self, T>, ReadySignals)
+    where
+        Self: Sized,
+    {
+        ReadySignal::new(self)
+    }
+
+    /// Returns a sink that retries sending an item to `self` up to `max` times on error.
+    ///
+    /// Each item is kept until `self` accepts it. If `poll_ready` or `start_send` fails, the item
+    /// is sent again, and the error is only returned after `max` retries. This is best-effort as
+    /// many sinks cannot recover from errors.
+    fn retry(self, max: usize) -> Retry<Self, T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        Retry::new(self, max)
+    }
+
+    /// Returns a sink that fails with [`TimeoutCloseError::Elapsed`] if closing `self` takes too
+    /// long.
+    ///
+    /// `ticks` is only polled while the returned sink is being closed. Once `ticks` yields more
+    /// than once before `self` is closed, closing fails instead of waiting for `self` any longer.
+    /// Items are forwarded unchanged. This gives deterministic timeouts, e.g. in tests.
+    fn timeout_close_via<Tk>(self, ticks: Tk) -> TimeoutCloseVia<Self, Tk, T>
+    where
+        Self: Sized,
+        Tk: Stream,
+    {
+        TimeoutCloseVia::new(self, ticks)
+    }
+
+    /// Returns a sink that buffers up to `capacity` items and reports the remaining room.
+    ///
+    /// This behaves like `buffer_items`, which requires the `std` feature, but
+    /// [`CapacitySink::remaining`] tells how many more items can be buffered, e.g. to monitor
+    /// back-pressure. A `capacity` of 0 is treated as 1.
+    #[cfg(feature = "alloc")]
+    fn with_capacity(self, capacity: usize) -> CapacitySink<Self, T>
+    where
+        Self: Sized,
+    {
+        CapacitySink::new(self, capacity)
+    }
+
+    /// Returns a sink that expands each item into zero or more items sent to `self`.
+    ///
+    /// Every item sent to the returned sink is passed to `f` and the items it returns are sent to
+    /// `self` in order. The returned sink is ready once all the items produced by the previous
+    /// call to `f` have been accepted by `self`.
+    #[cfg(feature = "alloc")]
+    fn with_fan<G, I, U>(self, f: G) -> WithFan<Self, G, U, I::Item>
+    where
+        Self: Sized,
+        G: FnMut(U) -> I,
+        I: IntoIterator<Item = T>,
+    {
+        WithFan::new(self, f)
+    }
+
+    /// Returns a sink that passes each item to `f` and sends the `Some` values to `self`.
+    ///
+    /// Items for which `f` returns `None` are dropped without reaching `self`. Unlike
+    /// [`SinkExt::with`], `f` is synchronous.
+    fn with_filter_map<G, U>(self, f: G) -> WithFilterMap<Self, G, U, T>
+    where
+        Self: Sized,
+        G: FnMut(U) -> Option<T>,
+    {
+        WithFilterMap::new(self, f)
+    }
+
+    /// Returns a sink that flushes `self` before closing it.
+    ///
+    /// Closing the returned sink drives `poll_flush` to completion before delegating to
+    /// `poll_close`, so that no buffered item is lost regardless of how `self` implements closing.
+    fn ensure_drained_on_close(self) -> DrainedClose<Self, T>
+    where
+        Self: Sized,
+    {
+        DrainedClose::new(self)
+    }
 }
 
 impl<T, S: Sink<T>> SinkTools<T> for S {}
+
+/// Returns a sink that calls `f` with each item.
+///
+/// The returned sink is always ready and errors returned by `f` are returned by `start_send`.
+pub fn from_fn<F, T, E>(f: F) -> FromFn<F>
+where
+    F: FnMut(T) -> Result<(), E>,
+{
+    FromFn::new(f)
+}
+
+#[cfg(feature = "alloc")]
+/// Returns a sink that distributes items among `sinks` proportionally to their weights.
+///
+/// Each sink is paired with its weight. Items are assigned using a smooth weighted round-robin:
+/// items are split in consecutive rounds whose length is the sum of the weights, and each sink
+/// receives exactly as many items per round as its weight. Within a round, items sent to a sink
+/// are interleaved with items sent to the other sinks rather than sent in bursts. Sinks with a
+/// weight of 0 never receive items, and items are dropped if all weights are 0.
+pub fn scatter_weighted<Sk, T>(sinks: Vec<(Sk, usize)>) -> ScatterWeighted<Sk, T>
+where
+    Sk: Sink<T> + Unpin,
+{
+    ScatterWeighted::new(sinks)
+}
+
+/// Returns a sink that routes each item to a sink dedicated to its key.
+///
+/// The key of each item is returned by `key_of`. The first item with a given key creates its sink
+/// by calling `make_sink`, and all items with that key are sent to it. Flushing or closing the
+/// returned sink flushes or closes all the sinks created so far.
+#[cfg(feature = "std")]
+pub fn split_by_key<K, Sk, G, M, T>(key_of: G, make_sink: M) -> SplitByKey<K, Sk, G, M, T>
+where
+    K: Eq + Hash,
+    Sk: Sink<T> + Unpin,
+    G: FnMut(&T) -> K,
+    M: FnMut(&K) -> Sk,
+{
+    SplitByKey::new(key_of, make_sink)
+}
+
+/// Returns a sink of `Either` values that sends `Left` values to `left` and `Right` values to
+/// `right`.
+///
+/// This behaves like [`fork`](SinkTools::fork) with a `switch` that returns its argument, which
+/// is convenient when items are already tagged upstream.
+pub fn split_either<SA, SB, L, R>(left: SA, right: SB) -> SplitEither<SA, SB, L, R>
+where
+    SA: Sink<L>,
+    SB: Sink<R, Error = SA::Error>,
+{
+    Fork::new(left, right, core::convert::identity)
+}
+
+/// Returns a sink of pairs that sends the first element to `left` and the second one to `right`.
+///
+/// Both elements of a pair must be accepted by their sink before the returned sink is ready again,
+/// so that either sink can slow down the other one. Closing the returned sink closes both sinks.
+pub fn unzip<SA, SB, A, B>(left: SA, right: SB) -> Unzip<SA, SB, A, B>
+where
+    SA: Sink<A>,
+    SB: Sink<B, Error = SA::Error>,
+{
+    Unzip::new(left, right)
+}
+
+/// Returns a sink of pairs that sends the first element to `a` and the second one to `b`.
+///
+/// The returned sink is ready when both sinks are ready. Errors from either sink are converted to
+/// `E`.
+pub fn zip_sinks<AS, BS, E>(a: AS, b: BS) -> ZipSinks<AS, BS, E> {
+    ZipSinks::new(a, b)
+}
+
+/// Closes `sink` unless `closed` indicates it is already closed, and records when it is
+fn close_once<S, T>(
+    sink: Pin<&mut S>,
+    closed: &mut bool,
+    ctx: &mut Context<'_>,
+) -> Poll<Result<(), S::Error>>
+where
+    S: Sink<T>,
+{
+    if *closed {
+        return Poll::Ready(Ok(()));
+    }
+    let res = sink.poll_close(ctx);
+    if let Poll::Ready(Ok(_)) = res {
+        *closed = true;
+    }
+    res
+}
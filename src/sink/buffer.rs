@@ -0,0 +1,184 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use futures::Sink;
+use pin_project::pin_project;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{ready, Context, Poll, Waker},
+};
+
+/// Sink returned by [`SinkTools::buffer_items`](crate::SinkTools::buffer_items).
+#[pin_project]
+#[derive(Debug)]
+pub struct BufferSink<S, T> {
+    #[pin]
+    sink: S,
+    queue: VecDeque<T>,
+    capacity: usize,
+    backlog: Arc<Mutex<Backlog>>,
+}
+
+impl<S, T> BufferSink<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        BufferSink {
+            sink,
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+            backlog: Default::default(),
+        }
+    }
+
+    /// Returns a future that resolves once at most `threshold` items are buffered.
+    ///
+    /// Buffered items are sent to the underlying sink when this sink is polled, so the returned
+    /// future only makes progress while this sink is being driven.
+    pub fn drained_below(&self, threshold: usize) -> DrainedBelow {
+        DrainedBelow {
+            backlog: self.backlog.clone(),
+            threshold,
+        }
+    }
+
+    fn poll_send_buffered(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+        keep: usize,
+    ) -> Poll<Result<(), S::Error>> {
+        let mut this = self.project();
+        while this.queue.len() > keep {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.queue.pop_front() {
+                this.sink.as_mut().start_send(item)?;
+            }
+            this.backlog.lock().unwrap().set_len(this.queue.len());
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S, T> Sink<T> for BufferSink<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let keep = self.capacity - 1;
+        self.poll_send_buffered(ctx, keep)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.queue.push_back(item);
+        this.backlog.lock().unwrap().set_len(this.queue.len());
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_buffered(ctx, 0)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_buffered(ctx, 0)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+/// Future returned by [`BufferSink::drained_below`].
+pub struct DrainedBelow {
+    backlog: Arc<Mutex<Backlog>>,
+    threshold: usize,
+}
+
+impl Debug for DrainedBelow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DrainedBelow")
+            .field("threshold", &self.threshold)
+            .finish()
+    }
+}
+
+impl Future for DrainedBelow {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<()> {
+        let mut backlog = self.backlog.lock().unwrap();
+        if backlog.len <= self.threshold {
+            Poll::Ready(())
+        } else {
+            backlog.wakers.push(ctx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Backlog {
+    len: usize,
+    wakers: Vec<Waker>,
+}
+
+impl Backlog {
+    fn set_len(&mut self, len: usize) {
+        let drained = len < self.len;
+        self.len = len;
+        if drained {
+            self.wakers.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{poll, SinkExt, StreamExt};
+
+    #[test]
+    fn drained_below_resolves_when_backlog_drains() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut sink = sender.buffer_items(4);
+        block_on(async {
+            for i in 0..4 {
+                sink.feed(i).await.unwrap();
+            }
+            let mut drained = sink.drained_below(1);
+            assert!(poll!(&mut drained).is_pending());
+            sink.flush().await.unwrap();
+            assert!(poll!(&mut drained).is_ready());
+        });
+        drop(sink);
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn buffered_items_are_sent_in_order_on_flush() {
+        let (sender, mut receiver) = mpsc::unbounded();
+        let mut sink = sender.buffer_items(3);
+        block_on(async {
+            for i in 0..3 {
+                sink.feed(i).await.unwrap();
+            }
+            assert!(poll!(receiver.next()).is_pending());
+            sink.flush().await.unwrap();
+        });
+        drop(sink);
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2]);
+    }
+}
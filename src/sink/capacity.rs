@@ -0,0 +1,112 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::with_capacity`](crate::SinkTools::with_capacity).
+#[pin_project]
+#[derive(Debug)]
+pub struct CapacitySink<S, T> {
+    #[pin]
+    sink: S,
+    queue: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<S, T> CapacitySink<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        CapacitySink {
+            sink,
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns how many more items can be buffered before items need to be sent to the underlying
+    /// sink
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.queue.len()
+    }
+
+    fn poll_send_buffered(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+        keep: usize,
+    ) -> Poll<Result<(), S::Error>> {
+        let mut this = self.project();
+        while this.queue.len() > keep {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.queue.pop_front() {
+                this.sink.as_mut().start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S, T> Sink<T> for CapacitySink<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let keep = self.capacity - 1;
+        self.poll_send_buffered(ctx, keep)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.project().queue.push_back(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_buffered(ctx, 0)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_buffered(ctx, 0)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{SinkExt, StreamExt};
+
+    #[test]
+    fn remaining_room_is_reported() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut sink = sender.with_capacity(3);
+        assert_eq!(sink.remaining(), 3);
+        block_on(async {
+            for i in 0..3 {
+                sink.feed(i).await.unwrap();
+            }
+            assert_eq!(sink.remaining(), 0);
+            sink.flush().await.unwrap();
+        });
+        assert_eq!(sink.remaining(), 3);
+        drop(sink);
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2]);
+    }
+}
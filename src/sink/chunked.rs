@@ -0,0 +1,100 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::vec::Vec;
+use core::{
+    mem,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::chunked`](crate::SinkTools::chunked).
+#[pin_project]
+#[derive(Debug)]
+pub struct Chunked<S, T> {
+    #[pin]
+    sink: S,
+    batch: Vec<T>,
+    size: usize,
+}
+
+impl<S, T> Chunked<S, T>
+where
+    S: Sink<Vec<T>>,
+{
+    pub(crate) fn new(sink: S, size: usize) -> Self {
+        assert!(size > 0, "Chunk size must be greater than 0");
+        Chunked {
+            sink,
+            batch: Vec::with_capacity(size),
+            size,
+        }
+    }
+
+    fn poll_send_batch(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+        min_len: usize,
+    ) -> Poll<Result<(), S::Error>> {
+        let mut this = self.project();
+        if !this.batch.is_empty() && this.batch.len() >= min_len {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            let batch = mem::replace(this.batch, Vec::with_capacity(*this.size));
+            this.sink.start_send(batch)?;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S, T> Sink<T> for Chunked<S, T>
+where
+    S: Sink<Vec<T>>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let size = self.size;
+        self.poll_send_batch(ctx, size)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.project().batch.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_batch(ctx, 1)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_send_batch(ctx, 1)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn items_are_sent_in_chunks() {
+        let (sender, receiver) = mpsc::unbounded();
+        block_on(stream::iter(0..10).map(Ok).forward(sender.chunked(3))).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(
+            received,
+            [vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9]]
+        );
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::sink::close_once;
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// What a [`CloseAfter`] sink does with items sent after its limit is reached
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Excess {
+    /// Silently drops the items
+    Drop,
+    /// Fails with [`CloseAfterError::Closed`]
+    Reject,
+}
+
+/// Error returned by [`CloseAfter`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CloseAfterError<E> {
+    /// An item was rejected because the limit was reached
+    Closed,
+    /// The underlying sink failed
+    Sink(E),
+}
+
+impl<E: Display> Display for CloseAfterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CloseAfterError::Closed => f.write_str("Sink closed after reaching its item limit"),
+            CloseAfterError::Sink(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for CloseAfterError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CloseAfterError::Closed => None,
+            CloseAfterError::Sink(e) => Some(e),
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::close_after`](crate::SinkTools::close_after).
+#[pin_project]
+#[derive(Debug)]
+pub struct CloseAfter<S, T> {
+    #[pin]
+    sink: S,
+    remaining: usize,
+    excess: Excess,
+    closed: bool,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, T> CloseAfter<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S, n: usize, excess: Excess) -> Self {
+        CloseAfter {
+            sink,
+            remaining: n,
+            excess,
+            closed: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Sink<T> for CloseAfter<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = CloseAfterError<S::Error>;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        if *this.remaining == 0 {
+            ready!(close_once(this.sink, this.closed, ctx)).map_err(CloseAfterError::Sink)?;
+            Poll::Ready(Ok(()))
+        } else {
+            this.sink.poll_ready(ctx).map_err(CloseAfterError::Sink)
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        if *this.remaining == 0 {
+            return match this.excess {
+                Excess::Drop => Ok(()),
+                Excess::Reject => Err(CloseAfterError::Closed),
+            };
+        }
+        *this.remaining -= 1;
+        this.sink.start_send(item).map_err(CloseAfterError::Sink)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        if *this.remaining == 0 {
+            close_once(this.sink, this.closed, ctx).map_err(CloseAfterError::Sink)
+        } else {
+            this.sink.poll_flush(ctx).map_err(CloseAfterError::Sink)
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        close_once(this.sink, this.closed, ctx).map_err(CloseAfterError::Sink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sink::{CloseAfterError, Excess},
+        SinkTools,
+    };
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, SinkExt, StreamExt};
+
+    #[test]
+    fn excess_items_are_dropped() {
+        let (sender, receiver) = mpsc::unbounded();
+        let numbers = stream::iter(0..100).map(Ok);
+        block_on(numbers.forward(sender.close_after(5, Excess::Drop))).unwrap();
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn excess_items_are_rejected() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut sink = sender.close_after(2, Excess::Reject);
+        block_on(async {
+            sink.send(0).await.unwrap();
+            sink.send(1).await.unwrap();
+            assert_eq!(sink.send(2).await, Err(CloseAfterError::Closed));
+        });
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1]);
+    }
+}
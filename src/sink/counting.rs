@@ -0,0 +1,84 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::sync::Arc;
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::counting`](crate::SinkTools::counting).
+#[pin_project]
+#[derive(Debug)]
+pub struct CountingSink<S, T> {
+    #[pin]
+    sink: S,
+    count: Arc<AtomicUsize>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, T> CountingSink<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S) -> (Self, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let sink = CountingSink {
+            sink,
+            count: count.clone(),
+            phantom: PhantomData,
+        };
+        (sink, count)
+    }
+}
+
+impl<S, T> Sink<T> for CountingSink<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_ready(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.sink.start_send(item)?;
+        this.count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn counter_reads_number_of_items_sent() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (sink, count) = sender.counting();
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        block_on(stream::iter(0..50).map(Ok).forward(sink)).unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 50);
+        assert_eq!(
+            block_on(receiver.collect::<Vec<_>>()),
+            (0..50).collect::<Vec<_>>()
+        );
+    }
+}
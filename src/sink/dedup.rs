@@ -0,0 +1,95 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::dedup`](crate::SinkTools::dedup).
+#[pin_project]
+#[derive(Debug)]
+pub struct DedupSink<S, T> {
+    #[pin]
+    sink: S,
+    last: Option<T>,
+    buffer: Option<T>,
+}
+
+impl<S, T> DedupSink<S, T>
+where
+    S: Sink<T>,
+    T: Clone + PartialEq,
+{
+    pub(crate) fn new(sink: S) -> Self {
+        DedupSink {
+            sink,
+            last: None,
+            buffer: None,
+        }
+    }
+}
+
+impl<S, T> Sink<T> for DedupSink<S, T>
+where
+    S: Sink<T>,
+    T: Clone + PartialEq,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if this.buffer.is_some() {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.buffer.take() {
+                this.sink.start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        if this.last.as_ref() != Some(&item) {
+            *this.last = Some(item.clone());
+            *this.buffer = Some(item);
+        }
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::stream;
+    use futures::StreamExt;
+
+    #[test]
+    fn consecutive_duplicates_are_dropped() {
+        let numbers = stream::iter([1, 1, 2, 2, 1]).map(Ok);
+        let (sender, receiver) = mpsc::unbounded();
+        block_on(numbers.forward(sender.dedup())).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(received, [1, 2, 1]);
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::sink::close_once;
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink and future returned by [`SinkTools::drain`](crate::SinkTools::drain).
+#[pin_project]
+#[derive(Debug)]
+pub struct Drain<S, T> {
+    #[pin]
+    sink: S,
+    count: usize,
+    closed: bool,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, T> Drain<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S) -> Self {
+        Drain {
+            sink,
+            count: 0,
+            closed: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of items accepted so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<S, T> Sink<T> for Drain<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _: T) -> Result<(), Self::Error> {
+        *self.project().count += 1;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        close_once(this.sink, this.closed, ctx)
+    }
+}
+
+impl<S, T> Future for Drain<S, T>
+where
+    S: Sink<T>,
+{
+    type Output = Result<usize, S::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        ready!(self.as_mut().poll_close(ctx)?);
+        Poll::Ready(Ok(self.count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn accepted_items_are_counted() {
+        let (sender, receiver) = mpsc::unbounded::<i32>();
+        let mut drain = sender.drain();
+        block_on(stream::iter(0..10).map(Ok).forward(&mut drain)).unwrap();
+        assert_eq!(block_on(drain), Ok(10));
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), []);
+    }
+}
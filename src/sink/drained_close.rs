@@ -0,0 +1,79 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by
+/// [`SinkTools::ensure_drained_on_close`](crate::SinkTools::ensure_drained_on_close).
+#[pin_project]
+#[derive(Debug)]
+pub struct DrainedClose<S, T> {
+    #[pin]
+    sink: S,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, T> DrainedClose<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S) -> Self {
+        DrainedClose {
+            sink,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Sink<T> for DrainedClose<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_ready(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.project().sink.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        ready!(this.sink.as_mut().poll_flush(ctx)?);
+        this.sink.poll_close(ctx)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{SinkExt, StreamExt};
+
+    #[test]
+    fn buffered_items_are_sent_before_closing() {
+        let (sender, receiver) = mpsc::channel(0);
+        let mut sink = sender.buffer(8).ensure_drained_on_close();
+        block_on(async {
+            for i in 0..5 {
+                sink.feed(i).await.unwrap();
+            }
+            let received = async { receiver.collect::<Vec<_>>().await };
+            let (closed, received) = futures::join!(sink.close(), received);
+            closed.unwrap();
+            assert_eq!(received, [0, 1, 2, 3, 4]);
+        });
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::filter`](crate::SinkTools::filter).
+#[pin_project]
+#[derive(Debug)]
+pub struct FilterSink<S, P, T> {
+    #[pin]
+    sink: S,
+    pred: P,
+    buffer: Option<T>,
+}
+
+impl<S, P, T> FilterSink<S, P, T>
+where
+    S: Sink<T>,
+    P: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(sink: S, pred: P) -> Self {
+        FilterSink {
+            sink,
+            pred,
+            buffer: None,
+        }
+    }
+}
+
+impl<S, P, T> Sink<T> for FilterSink<S, P, T>
+where
+    S: Sink<T>,
+    P: FnMut(&T) -> bool,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if this.buffer.is_some() {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.buffer.take() {
+                this.sink.start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        if (this.pred)(&item) {
+            *this.buffer = Some(item);
+        }
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::stream;
+    use futures::StreamExt;
+
+    #[test]
+    fn it_works() {
+        let numbers = stream::iter(0..10).map(Ok);
+        let (sender, receiver) = mpsc::unbounded();
+        let res = numbers.forward(sender.filter(|n: &u32| n % 2 == 0));
+        block_on(res).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(received, [0, 2, 4, 6, 8]);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::flush_every`](crate::SinkTools::flush_every).
+#[pin_project]
+#[derive(Debug)]
+pub struct FlushEvery<S, T> {
+    #[pin]
+    sink: S,
+    n: usize,
+    unflushed: usize,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, T> FlushEvery<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S, n: usize) -> Self {
+        FlushEvery {
+            sink,
+            n: n.max(1),
+            unflushed: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Sink<T> for FlushEvery<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if *this.unflushed >= *this.n {
+            ready!(this.sink.as_mut().poll_flush(ctx)?);
+            *this.unflushed = 0;
+        }
+        this.sink.poll_ready(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.sink.start_send(item)?;
+        *this.unflushed += 1;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        ready!(this.sink.poll_flush(ctx)?);
+        *this.unflushed = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::executor::block_on;
+    use futures::{Sink, SinkExt};
+    use std::{
+        convert::Infallible,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Item(u32),
+        Flush,
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder(Vec<Event>);
+
+    impl Sink<u32> for Recorder {
+        type Error = Infallible;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: u32) -> Result<(), Infallible> {
+            self.0.push(Event::Item(item));
+            Ok(())
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Result<(), Infallible>> {
+            self.0.push(Event::Flush);
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn inner_sink_is_flushed_every_n_items() {
+        let mut recorder = Recorder::default();
+        block_on(async {
+            let mut sink = (&mut recorder).flush_every(2);
+            for i in 0..5 {
+                sink.feed(i).await.unwrap();
+            }
+            sink.flush().await.unwrap();
+            sink.feed(5).await.unwrap();
+        });
+        let expected = [
+            Event::Item(0),
+            Event::Item(1),
+            Event::Flush,
+            Event::Item(2),
+            Event::Item(3),
+            Event::Flush,
+            Event::Item(4),
+            Event::Flush,
+            Event::Item(5),
+        ];
+        assert_eq!(recorder.0, expected);
+    }
+}
@@ -1,13 +1,15 @@
 // Copyright (C) 2018-2022 Stephane Raux. Distributed under the 0BSD license.
 
-use either::{Either, Left, Right};
-use futures::Sink;
-use pin_project::pin_project;
-use std::{
+use crate::sink::close_once;
+use core::{
+    fmt::{self, Display},
     marker::PhantomData,
     pin::Pin,
     task::{ready, Context, Poll},
 };
+use either::{Either, Left, Right};
+use futures::{sink::SinkMapErr, Sink};
+use pin_project::pin_project;
 
 /// Sink returned by [`SinkTools::fork`](crate::SinkTools::fork).
 #[pin_project]
@@ -47,6 +49,25 @@ where
     }
 }
 
+impl<T, LS, RS, F, LV, RV> Fork<T, LS, RS, F, LV, RV>
+where
+    LS: Sink<LV>,
+    RS: Sink<RV>,
+{
+    /// Returns references to the underlying sinks
+    pub fn get_ref(&self) -> (&LS, &RS) {
+        (&self.left_sink, &self.right_sink)
+    }
+
+    /// Returns the underlying sinks
+    ///
+    /// An item accepted by this sink but not yet sent to an underlying sink is dropped. Flushing
+    /// this sink beforehand ensures no item is lost.
+    pub fn into_inner(self) -> (LS, RS) {
+        (self.left_sink, self.right_sink)
+    }
+}
+
 impl<T, LS, RS, F, LV, RV> Sink<T> for Fork<T, LS, RS, F, LV, RV>
 where
     F: FnMut(T) -> Either<LV, RV>,
@@ -79,6 +100,243 @@ where
         Ok(())
     }
 
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let buffer_res = self.as_mut().poll_ready(ctx)?;
+        let this = self.project();
+        let left_res = this.left_sink.poll_flush(ctx);
+        let right_res = this.right_sink.poll_flush(ctx);
+        match (buffer_res, left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let this = self.project();
+        let left_res = close_once(this.left_sink, this.left_closed, ctx);
+        let right_res = close_once(this.right_sink, this.right_closed, ctx);
+        match (left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::fork_buffered`](crate::SinkTools::fork_buffered).
+#[pin_project]
+#[derive(Debug)]
+pub struct ForkBuffered<T, LS, RS, F, LV, RV>
+where
+    LS: Sink<LV>,
+    RS: Sink<RV>,
+{
+    #[pin]
+    left_sink: LS,
+    #[pin]
+    right_sink: RS,
+    switch: F,
+    left_closed: bool,
+    right_closed: bool,
+    left_buffer: Option<LV>,
+    right_buffer: Option<RV>,
+    next: Option<Either<LV, RV>>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T, LS, RS, F, LV, RV> ForkBuffered<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Either<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    pub(crate) fn new(left_sink: LS, right_sink: RS, switch: F) -> Self {
+        ForkBuffered {
+            left_sink,
+            right_sink,
+            switch,
+            left_closed: false,
+            right_closed: false,
+            left_buffer: None,
+            right_buffer: None,
+            next: None,
+            phantom: PhantomData,
+        }
+    }
+
+    fn is_drained(&self) -> bool {
+        self.left_buffer.is_none() && self.right_buffer.is_none() && self.next.is_none()
+    }
+}
+
+impl<T, LS, RS, F, LV, RV> Sink<T> for ForkBuffered<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Either<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    type Error = LS::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        loop {
+            let _ = send_buffered(this.left_sink.as_mut(), this.left_buffer, ctx)?;
+            let _ = send_buffered(this.right_sink.as_mut(), this.right_buffer, ctx)?;
+            match this.next.take() {
+                Some(Left(item)) if this.left_buffer.is_none() => *this.left_buffer = Some(item),
+                Some(Right(item)) if this.right_buffer.is_none() => *this.right_buffer = Some(item),
+                next => {
+                    *this.next = next;
+                    break;
+                }
+            }
+        }
+        if this.next.is_none() {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.next.is_none());
+        *this.next = Some((this.switch)(item));
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let _ = self.as_mut().poll_ready(ctx)?;
+        let drained = self.is_drained();
+        let this = self.project();
+        let left_res = this.left_sink.poll_flush(ctx);
+        let right_res = this.right_sink.poll_flush(ctx);
+        match (drained, left_res?, right_res?) {
+            (true, Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        if !self.is_drained() {
+            return Poll::Pending;
+        }
+        let this = self.project();
+        let left_res = close_once(this.left_sink, this.left_closed, ctx);
+        let right_res = close_once(this.right_sink, this.right_closed, ctx);
+        match (left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::ordered_fork`](crate::SinkTools::ordered_fork).
+#[pin_project]
+#[derive(Debug)]
+pub struct OrderedFork<T, LS, RS, F, LV, RV>
+where
+    LS: Sink<LV>,
+    RS: Sink<RV>,
+{
+    #[pin]
+    left_sink: LS,
+    #[pin]
+    right_sink: RS,
+    switch: F,
+    left_closed: bool,
+    right_closed: bool,
+    left_unflushed: bool,
+    right_unflushed: bool,
+    buffer: Option<Either<LV, RV>>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T, LS, RS, F, LV, RV> OrderedFork<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Either<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    pub(crate) fn new(left_sink: LS, right_sink: RS, switch: F) -> Self {
+        OrderedFork {
+            left_sink,
+            right_sink,
+            switch,
+            left_closed: false,
+            right_closed: false,
+            left_unflushed: false,
+            right_unflushed: false,
+            buffer: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, LS, RS, F, LV, RV> Sink<T> for OrderedFork<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Either<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    type Error = LS::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        match this.buffer.take() {
+            Some(Left(item)) => match this.left_sink.as_mut().poll_ready(ctx)? {
+                Poll::Ready(()) => {
+                    this.left_sink.as_mut().start_send(item)?;
+                    *this.left_unflushed = true;
+                }
+                Poll::Pending => {
+                    *this.buffer = Some(Left(item));
+                    return Poll::Pending;
+                }
+            },
+            Some(Right(item)) => match this.right_sink.as_mut().poll_ready(ctx)? {
+                Poll::Ready(()) => {
+                    this.right_sink.as_mut().start_send(item)?;
+                    *this.right_unflushed = true;
+                }
+                Poll::Pending => {
+                    *this.buffer = Some(Right(item));
+                    return Poll::Pending;
+                }
+            },
+            None => {}
+        }
+        if *this.left_unflushed {
+            ready!(this.left_sink.poll_flush(ctx)?);
+            *this.left_unflushed = false;
+        }
+        if *this.right_unflushed {
+            ready!(this.right_sink.poll_flush(ctx)?);
+            *this.right_unflushed = false;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        *this.buffer = Some((this.switch)(item));
+        Ok(())
+    }
+
     fn poll_flush(
         mut self: Pin<&mut Self>,
         ctx: &mut Context<'_>,
@@ -99,24 +357,294 @@ where
     ) -> Poll<Result<(), Self::Error>> {
         ready!(self.as_mut().poll_ready(ctx)?);
         let this = self.project();
-        let left_res = if *this.left_closed {
-            Poll::Ready(Ok(()))
-        } else {
-            let res = this.left_sink.poll_close(ctx);
-            if let Poll::Ready(Ok(_)) = res {
-                *this.left_closed = true;
-            }
-            res
+        let left_res = close_once(this.left_sink, this.left_closed, ctx);
+        let right_res = close_once(this.right_sink, this.right_closed, ctx);
+        match (left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::fork_with_err`](crate::SinkTools::fork_with_err).
+pub type ForkWithErr<T, LS, RS, F, LV, RV, E> = Fork<
+    T,
+    SinkMapErr<LS, fn(<LS as Sink<LV>>::Error) -> E>,
+    SinkMapErr<RS, fn(<RS as Sink<RV>>::Error) -> E>,
+    F,
+    LV,
+    RV,
+>;
+
+/// Error returned by [`FallibleFork`], telling which underlying sink failed
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ForkError<LE, RE> {
+    /// The left sink failed
+    Left(LE),
+    /// The right sink failed
+    Right(RE),
+}
+
+impl<LE: Display, RE: Display> Display for ForkError<LE, RE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForkError::Left(e) => write!(f, "Left sink failed: {e}"),
+            ForkError::Right(e) => write!(f, "Right sink failed: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<LE, RE> std::error::Error for ForkError<LE, RE>
+where
+    LE: std::error::Error + 'static,
+    RE: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ForkError::Left(e) => Some(e),
+            ForkError::Right(e) => Some(e),
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::fallible_fork`](crate::SinkTools::fallible_fork).
+pub type FallibleFork<T, LS, RS, F, LV, RV> =
+    ForkWithErr<T, LS, RS, F, LV, RV, ForkError<<LS as Sink<LV>>::Error, <RS as Sink<RV>>::Error>>;
+
+/// Sink returned by [`split_either`](crate::sink::split_either).
+pub type SplitEither<LS, RS, LV, RV> =
+    Fork<Either<LV, RV>, LS, RS, fn(Either<LV, RV>) -> Either<LV, RV>, LV, RV>;
+
+/// Item routed by [`Fork3`] to one of its three sinks
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Choice3<A, B, C> {
+    /// Item for the first sink
+    First(A),
+    /// Item for the second sink
+    Second(B),
+    /// Item for the third sink
+    Third(C),
+}
+
+/// Sink returned by [`SinkTools::fork3`](crate::SinkTools::fork3).
+#[pin_project]
+#[derive(Debug)]
+pub struct Fork3<T, S1, S2, S3, F, V1, V2, V3>
+where
+    S1: Sink<V1>,
+    S2: Sink<V2>,
+    S3: Sink<V3>,
+{
+    #[pin]
+    first_sink: S1,
+    #[pin]
+    second_sink: S2,
+    #[pin]
+    third_sink: S3,
+    switch: F,
+    first_closed: bool,
+    second_closed: bool,
+    third_closed: bool,
+    buffer: Option<Choice3<V1, V2, V3>>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T, S1, S2, S3, F, V1, V2, V3> Fork3<T, S1, S2, S3, F, V1, V2, V3>
+where
+    F: FnMut(T) -> Choice3<V1, V2, V3>,
+    S1: Sink<V1>,
+    S2: Sink<V2, Error = S1::Error>,
+    S3: Sink<V3, Error = S1::Error>,
+{
+    pub(crate) fn new(first_sink: S1, second_sink: S2, third_sink: S3, switch: F) -> Self {
+        Fork3 {
+            first_sink,
+            second_sink,
+            third_sink,
+            switch,
+            first_closed: false,
+            second_closed: false,
+            third_closed: false,
+            buffer: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S1, S2, S3, F, V1, V2, V3> Sink<T> for Fork3<T, S1, S2, S3, F, V1, V2, V3>
+where
+    F: FnMut(T) -> Choice3<V1, V2, V3>,
+    S1: Sink<V1>,
+    S2: Sink<V2, Error = S1::Error>,
+    S3: Sink<V3, Error = S1::Error>,
+{
+    type Error = S1::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        let (res, buffer) = match this.buffer.take() {
+            Some(Choice3::First(item)) => match this.first_sink.as_mut().poll_ready(ctx) {
+                Poll::Ready(Ok(())) => (Poll::Ready(this.first_sink.start_send(item)), None),
+                res => (res, Some(Choice3::First(item))),
+            },
+            Some(Choice3::Second(item)) => match this.second_sink.as_mut().poll_ready(ctx) {
+                Poll::Ready(Ok(())) => (Poll::Ready(this.second_sink.start_send(item)), None),
+                res => (res, Some(Choice3::Second(item))),
+            },
+            Some(Choice3::Third(item)) => match this.third_sink.as_mut().poll_ready(ctx) {
+                Poll::Ready(Ok(())) => (Poll::Ready(this.third_sink.start_send(item)), None),
+                res => (res, Some(Choice3::Third(item))),
+            },
+            None => (Poll::Ready(Ok(())), None),
         };
-        let right_res = if *this.right_closed {
-            Poll::Ready(Ok(()))
-        } else {
-            let res = this.right_sink.poll_close(ctx);
-            if let Poll::Ready(Ok(_)) = res {
-                *this.right_closed = true;
+        *this.buffer = buffer;
+        res
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        *this.buffer = Some((this.switch)(item));
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let buffer_res = self.as_mut().poll_ready(ctx)?;
+        let this = self.project();
+        let first_res = this.first_sink.poll_flush(ctx);
+        let second_res = this.second_sink.poll_flush(ctx);
+        let third_res = this.third_sink.poll_flush(ctx);
+        match (buffer_res, first_res?, second_res?, third_res?) {
+            (Poll::Ready(_), Poll::Ready(_), Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let this = self.project();
+        let first_res = close_once(this.first_sink, this.first_closed, ctx);
+        let second_res = close_once(this.second_sink, this.second_closed, ctx);
+        let third_res = close_once(this.third_sink, this.third_closed, ctx);
+        match (first_res?, second_res?, third_res?) {
+            (Poll::Ready(_), Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Item routed by [`ForkBroadcast`] to one or both of its sinks
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Broadcast<L, R> {
+    /// Item for the left sink only
+    JustLeft(L),
+    /// Item for the right sink only
+    JustRight(R),
+    /// Items for both sinks
+    Both(L, R),
+}
+
+/// Sink returned by [`SinkTools::fork_broadcast`](crate::SinkTools::fork_broadcast).
+#[pin_project]
+#[derive(Debug)]
+pub struct ForkBroadcast<T, LS, RS, F, LV, RV>
+where
+    LS: Sink<LV>,
+    RS: Sink<RV>,
+{
+    #[pin]
+    left_sink: LS,
+    #[pin]
+    right_sink: RS,
+    switch: F,
+    left_closed: bool,
+    right_closed: bool,
+    left_buffer: Option<LV>,
+    right_buffer: Option<RV>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T, LS, RS, F, LV, RV> ForkBroadcast<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Broadcast<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    pub(crate) fn new(left_sink: LS, right_sink: RS, switch: F) -> Self {
+        ForkBroadcast {
+            left_sink,
+            right_sink,
+            switch,
+            left_closed: false,
+            right_closed: false,
+            left_buffer: None,
+            right_buffer: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, LS, RS, F, LV, RV> Sink<T> for ForkBroadcast<T, LS, RS, F, LV, RV>
+where
+    F: FnMut(T) -> Broadcast<LV, RV>,
+    LS: Sink<LV>,
+    RS: Sink<RV, Error = LS::Error>,
+{
+    type Error = LS::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let left_res = send_buffered(this.left_sink, this.left_buffer, ctx);
+        let right_res = send_buffered(this.right_sink, this.right_buffer, ctx);
+        match (left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.left_buffer.is_none() && this.right_buffer.is_none());
+        match (this.switch)(item) {
+            Broadcast::JustLeft(item) => *this.left_buffer = Some(item),
+            Broadcast::JustRight(item) => *this.right_buffer = Some(item),
+            Broadcast::Both(left, right) => {
+                *this.left_buffer = Some(left);
+                *this.right_buffer = Some(right);
             }
-            res
-        };
+        }
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let buffer_res = self.as_mut().poll_ready(ctx)?;
+        let this = self.project();
+        let left_res = this.left_sink.poll_flush(ctx);
+        let right_res = this.right_sink.poll_flush(ctx);
+        match (buffer_res, left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let this = self.project();
+        let left_res = close_once(this.left_sink, this.left_closed, ctx);
+        let right_res = close_once(this.right_sink, this.right_closed, ctx);
         match (left_res?, right_res?) {
             (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
             _ => Poll::Pending,
@@ -124,14 +652,83 @@ where
     }
 }
 
+/// Sends the item in `buffer` to `sink` if there is one and `sink` is ready
+fn send_buffered<S, T>(
+    mut sink: Pin<&mut S>,
+    buffer: &mut Option<T>,
+    ctx: &mut Context<'_>,
+) -> Poll<Result<(), S::Error>>
+where
+    S: Sink<T>,
+{
+    if buffer.is_some() {
+        ready!(sink.as_mut().poll_ready(ctx)?);
+        if let Some(item) = buffer.take() {
+            sink.start_send(item)?;
+        }
+    }
+    Poll::Ready(Ok(()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::SinkTools;
-    use either::{Left, Right};
+    use crate::{
+        sink::{split_either, Broadcast, Choice3, ForkError},
+        SinkTools,
+    };
+    use either::{Either, Left, Right};
     use futures::channel::mpsc;
     use futures::executor::block_on;
     use futures::stream;
-    use futures::{SinkExt, StreamExt};
+    use futures::{Sink, SinkExt, StreamExt};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum ForwardError {
+        Stream,
+        Sink,
+    }
+
+    impl From<mpsc::SendError> for ForwardError {
+        fn from(_: mpsc::SendError) -> Self {
+            ForwardError::Sink
+        }
+    }
+
+    fn parity(n: u32) -> Either<u32, u32> {
+        if n % 2 == 0 {
+            Left(n)
+        } else {
+            Right(n)
+        }
+    }
+
+    #[test]
+    fn stream_errors_are_returned_by_forward() {
+        let items = [Ok(0), Ok(1), Err(ForwardError::Stream), Ok(2)];
+        let (even_sender, even_receiver) = mpsc::unbounded();
+        let (odd_sender, odd_receiver) = mpsc::unbounded();
+        let mut sink = even_sender.fork_with_err(odd_sender, parity);
+        let res = block_on(stream::iter(items).forward(&mut sink));
+        assert_eq!(res, Err(ForwardError::Stream));
+        block_on(sink.close()).unwrap();
+        drop(sink);
+        assert_eq!(block_on(even_receiver.collect::<Vec<_>>()), [0]);
+        assert_eq!(block_on(odd_receiver.collect::<Vec<_>>()), [1]);
+    }
+
+    #[test]
+    fn sink_errors_are_converted_when_forwarding() {
+        let (even_sender, even_receiver) = mpsc::unbounded::<u32>();
+        let (odd_sender, _odd_receiver) = mpsc::unbounded();
+        drop(even_receiver);
+        let mut sink = even_sender.fork_with_err(odd_sender, parity);
+        let res = block_on(stream::iter(0..4).map(Ok).forward(&mut sink));
+        assert_eq!(res, Err(ForwardError::Sink));
+    }
 
     #[test]
     fn it_works() {
@@ -150,4 +747,242 @@ mod tests {
         assert_eq!(received_evens, even_nums);
         assert_eq!(received_odds, odd_nums);
     }
+
+    #[test]
+    fn split_either_routes_by_side() {
+        let (left_sender, left_receiver) = mpsc::unbounded();
+        let (right_sender, right_receiver) = mpsc::unbounded();
+        let items = [
+            Left(0),
+            Right('a'),
+            Right('b'),
+            Left(1),
+            Left(2),
+            Right('c'),
+        ];
+        let sink = split_either(left_sender, right_sender);
+        block_on(stream::iter(items).map(Ok).forward(sink)).unwrap();
+        assert_eq!(block_on(left_receiver.collect::<Vec<_>>()), [0, 1, 2]);
+        assert_eq!(
+            block_on(right_receiver.collect::<Vec<_>>()),
+            ['a', 'b', 'c']
+        );
+    }
+
+    #[test]
+    fn boxed_trait_object_sinks_can_be_forked() {
+        type BoxedSink = Pin<Box<dyn Sink<u32, Error = mpsc::SendError>>>;
+        let (even_sender, even_receiver) = mpsc::unbounded();
+        let (odd_sender, odd_receiver) = mpsc::unbounded();
+        let mut sinks: Vec<BoxedSink> = vec![Box::pin(even_sender), Box::pin(odd_sender)];
+        let odd_sink = sinks.pop().unwrap();
+        let even_sink = sinks.pop().unwrap();
+        let forked = even_sink.fork(odd_sink, |n| if n % 2 == 0 { Left(n) } else { Right(n) });
+        sinks.push(Box::pin(forked));
+        block_on(stream::iter(0..6).map(Ok).forward(&mut sinks[0])).unwrap();
+        drop(sinks);
+        assert_eq!(block_on(even_receiver.collect::<Vec<_>>()), [0, 2, 4]);
+        assert_eq!(block_on(odd_receiver.collect::<Vec<_>>()), [1, 3, 5]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flushing_reaches_other_sink_when_buffered_item_is_pending() {
+        use futures::{task::noop_waker_ref, Sink};
+        use std::pin::pin;
+        use std::task::{Context, Poll};
+
+        let (left_sender, _left_receiver) = mpsc::channel(0);
+        let (right_sender, mut right_receiver) = mpsc::unbounded();
+        let mut fork = pin!(left_sender.fork(right_sender.buffer(4), |n: u32| {
+            if n % 2 == 1 {
+                Right(n)
+            } else {
+                Left(n)
+            }
+        }));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        for n in [1, 0, 2] {
+            assert!(matches!(
+                fork.as_mut().poll_ready(&mut ctx),
+                Poll::Ready(Ok(()))
+            ));
+            fork.as_mut().start_send(n).unwrap();
+        }
+        assert!(fork.as_mut().poll_flush(&mut ctx).is_pending());
+        assert_eq!(right_receiver.try_recv(), Ok(1));
+    }
+
+    #[test]
+    fn items_are_routed_to_three_sinks() {
+        let numbers = stream::iter(0..9).map(Ok::<u32, ()>);
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..3).map(|_| mpsc::unbounded()).unzip();
+        let mut senders = senders.into_iter();
+        let mut next_sender = || senders.next().unwrap().sink_map_err(|_| ());
+        let (first, second, third) = (next_sender(), next_sender(), next_sender());
+        let res = numbers.forward(first.fork3(second, third, |n| match n % 3 {
+            0 => Choice3::First(n),
+            1 => Choice3::Second(n),
+            _ => Choice3::Third(n),
+        }));
+        block_on(res).unwrap();
+        let received = receivers
+            .into_iter()
+            .map(|r| block_on(r.collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(received, [[0, 3, 6], [1, 4, 7], [2, 5, 8]]);
+    }
+
+    #[test]
+    fn sinks_with_different_errors_can_be_forked() {
+        #[derive(Debug, PartialEq)]
+        enum Error {
+            Channel,
+            Drain,
+        }
+
+        impl From<mpsc::SendError> for Error {
+            fn from(_: mpsc::SendError) -> Self {
+                Error::Channel
+            }
+        }
+
+        impl From<std::convert::Infallible> for Error {
+            fn from(_: std::convert::Infallible) -> Self {
+                Error::Drain
+            }
+        }
+
+        let switch = |n: u32| {
+            if n % 2 == 0 {
+                Left(n)
+            } else {
+                Right(n)
+            }
+        };
+        let numbers = stream::iter(0..10).map(Ok);
+        let (even_sender, even_receiver) = mpsc::unbounded();
+        let sink = even_sender.fork_with_err::<Error, _, _, _, _>(futures::sink::drain(), switch);
+        block_on(numbers.forward(sink)).unwrap();
+        let received_evens = block_on(even_receiver.collect::<Vec<_>>());
+        assert_eq!(received_evens, [0, 2, 4, 6, 8]);
+
+        let (closed_sender, _) = mpsc::unbounded();
+        let mut sink =
+            closed_sender.fork_with_err::<Error, _, _, _, _>(futures::sink::drain(), switch);
+        assert_eq!(block_on(sink.send(0)), Err(Error::Channel));
+    }
+
+    #[test]
+    fn items_can_be_sent_to_both_sinks() {
+        let (left_sender, left_receiver) = mpsc::unbounded();
+        let (right_sender, right_receiver) = mpsc::unbounded();
+        let sink = left_sender.fork_broadcast(right_sender, |n: u32| match (n < 3, n % 2 == 0) {
+            (true, true) => Broadcast::Both(n, n),
+            (true, false) => Broadcast::JustLeft(n),
+            _ => Broadcast::JustRight(n),
+        });
+        block_on(stream::iter(0..6).map(Ok).forward(sink)).unwrap();
+        assert_eq!(block_on(left_receiver.collect::<Vec<_>>()), [0, 1, 2]);
+        assert_eq!(
+            block_on(right_receiver.collect::<Vec<_>>()),
+            [0, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn sinks_can_be_recovered() {
+        let (even_sender, even_receiver) = mpsc::unbounded();
+        let (odd_sender, odd_receiver) = mpsc::unbounded();
+        let mut sink = even_sender.fork(odd_sender, parity);
+        block_on(sink.send_all(&mut stream::iter(0..4).map(Ok))).unwrap();
+        let (even_sender, odd_sender) = sink.get_ref();
+        assert!(!even_sender.is_closed() && !odd_sender.is_closed());
+        let (mut even_sender, mut odd_sender) = sink.into_inner();
+        block_on(even_sender.send(10)).unwrap();
+        block_on(odd_sender.send(11)).unwrap();
+        drop((even_sender, odd_sender));
+        assert_eq!(block_on(even_receiver.collect::<Vec<_>>()), [0, 2, 10]);
+        assert_eq!(block_on(odd_receiver.collect::<Vec<_>>()), [1, 3, 11]);
+    }
+
+    #[test]
+    fn buffered_fork_sends_to_other_sink_while_one_is_stuck() {
+        use futures::{task::noop_waker_ref, Sink};
+        use std::pin::pin;
+        use std::task::{Context, Poll};
+
+        let (left_sender, _left_receiver) = mpsc::channel(0);
+        let (right_sender, mut right_receiver) = mpsc::unbounded();
+        let mut fork = pin!(left_sender.fork_buffered(right_sender, parity));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        for n in [0, 2, 1, 3, 5] {
+            assert!(matches!(
+                fork.as_mut().poll_ready(&mut ctx),
+                Poll::Ready(Ok(()))
+            ));
+            fork.as_mut().start_send(n).unwrap();
+        }
+        assert!(matches!(
+            fork.as_mut().poll_ready(&mut ctx),
+            Poll::Ready(Ok(()))
+        ));
+        for n in [1, 3, 5] {
+            assert_eq!(right_receiver.try_recv(), Ok(n));
+        }
+        fork.as_mut().start_send(4).unwrap();
+        assert!(fork.as_mut().poll_ready(&mut ctx).is_pending());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ordered_fork_preserves_global_order() {
+        let (sender, receiver) = mpsc::unbounded();
+        let left = sender.clone().buffer_items(4);
+        let right = sender.buffer_items(4);
+        let mut sink = left.ordered_fork(right, parity);
+        block_on(sink.send_all(&mut stream::iter([0, 1, 3, 2, 4, 5]).map(Ok))).unwrap();
+        drop(sink);
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 3, 2, 4, 5]);
+    }
+
+    #[test]
+    fn fallible_fork_tells_which_sink_failed() {
+        struct FailOnClose;
+
+        impl Sink<u32> for FailOnClose {
+            type Error = &'static str;
+
+            fn poll_ready(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn start_send(self: Pin<&mut Self>, _: u32) -> Result<(), Self::Error> {
+                Ok(())
+            }
+
+            fn poll_flush(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn poll_close(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                Poll::Ready(Err("closing failed"))
+            }
+        }
+
+        let (even_sender, even_receiver) = mpsc::unbounded();
+        let sink = even_sender.fallible_fork(FailOnClose, parity);
+        let res = block_on(stream::iter(0..4).map(Ok).forward(sink));
+        assert_eq!(res, Err(ForkError::Right("closing failed")));
+        assert_eq!(block_on(even_receiver.collect::<Vec<_>>()), [0, 2]);
+    }
 }
@@ -0,0 +1,81 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    future::FusedFuture,
+    stream::{Forward, Map},
+    Sink, Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+type Fallible<St, E> = Map<St, fn(<St as Stream>::Item) -> Result<<St as Stream>::Item, E>>;
+
+/// Future returned by [`SinkTools::forward_from`](crate::SinkTools::forward_from).
+#[pin_project]
+pub struct ForwardFrom<Si, St, T>(#[pin] Forward<Fallible<St, Si::Error>, Si>)
+where
+    Si: Sink<T>,
+    St: Stream<Item = T>;
+
+impl<Si, St, T> ForwardFrom<Si, St, T>
+where
+    Si: Sink<T>,
+    St: Stream<Item = T>,
+{
+    pub(crate) fn new(sink: Si, stream: St) -> Self {
+        Self(stream.map(Ok as fn(_) -> _).forward(sink))
+    }
+}
+
+impl<Si, St, T> Debug for ForwardFrom<Si, St, T>
+where
+    Si: Sink<T>,
+    St: Stream<Item = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ForwardFrom")
+    }
+}
+
+impl<Si, St, T> Future for ForwardFrom<Si, St, T>
+where
+    Si: Sink<T>,
+    St: Stream<Item = T>,
+{
+    type Output = Result<(), Si::Error>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().0.poll(ctx)
+    }
+}
+
+impl<Si, St, T> FusedFuture for ForwardFrom<Si, St, T>
+where
+    Si: Sink<T>,
+    St: Stream<Item = T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn stream_is_forwarded_and_sink_is_closed() {
+        let (sender, receiver) = mpsc::unbounded();
+        block_on(sender.forward_from(stream::iter(0..10))).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(received, (0..10).collect::<Vec<_>>());
+    }
+}
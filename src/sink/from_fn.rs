@@ -0,0 +1,66 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Sink;
+
+/// Sink returned by [`from_fn`](crate::sink::from_fn).
+#[derive(Clone, Debug)]
+pub struct FromFn<F>(F);
+
+impl<F> FromFn<F> {
+    pub(crate) fn new(f: F) -> Self {
+        FromFn(f)
+    }
+}
+
+impl<F> Unpin for FromFn<F> {}
+
+impl<F, T, E> Sink<T> for FromFn<F>
+where
+    F: FnMut(T) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), E>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), E> {
+        (self.0)(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), E>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), E>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sink::from_fn;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn items_are_passed_to_function() {
+        let mut received = Vec::new();
+        let sink = from_fn(|n| {
+            received.push(n);
+            Ok::<_, ()>(())
+        });
+        block_on(stream::iter(0..5).map(Ok).forward(sink)).unwrap();
+        assert_eq!(received, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn errors_are_returned() {
+        let sink = from_fn(|n: u32| if n < 2 { Ok(()) } else { Err(n) });
+        assert_eq!(block_on(stream::iter(0..5).map(Ok).forward(sink)), Err(2));
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::inspect`](crate::SinkTools::inspect).
+#[pin_project]
+#[derive(Debug)]
+pub struct InspectSink<S, G, T> {
+    #[pin]
+    sink: S,
+    f: G,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, G, T> InspectSink<S, G, T>
+where
+    S: Sink<T>,
+    G: FnMut(&T),
+{
+    pub(crate) fn new(sink: S, f: G) -> Self {
+        InspectSink {
+            sink,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, G, T> Sink<T> for InspectSink<S, G, T>
+where
+    S: Sink<T>,
+    G: FnMut(&T),
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_ready(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        (this.f)(&item);
+        this.sink.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+    use std::cell::RefCell;
+
+    #[test]
+    fn inspected_items_are_forwarded() {
+        let inspected = RefCell::new(Vec::new());
+        let (sender, receiver) = mpsc::unbounded();
+        let sink = SinkTools::inspect(sender, |&n| inspected.borrow_mut().push(n));
+        block_on(stream::iter(0..5).map(Ok).forward(sink)).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(received, [0, 1, 2, 3, 4]);
+        assert_eq!(inspected.into_inner(), received);
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::map_item`](crate::SinkTools::map_item).
+#[pin_project]
+#[derive(Debug)]
+pub struct MapItem<S, G, T, U> {
+    #[pin]
+    sink: S,
+    f: G,
+    phantom: PhantomData<fn(T) -> U>,
+}
+
+impl<S, G, T, U> MapItem<S, G, T, U>
+where
+    S: Sink<U>,
+    G: FnMut(T) -> U,
+{
+    pub(crate) fn new(sink: S, f: G) -> Self {
+        MapItem {
+            sink,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, G, T, U> Sink<T> for MapItem<S, G, T, U>
+where
+    S: Sink<U>,
+    G: FnMut(T) -> U,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_ready(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.sink.start_send((this.f)(item))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn items_are_mapped_before_being_sent() {
+        let (sender, receiver) = mpsc::unbounded();
+        let sink = sender.map_item(|n: i32| n.to_string());
+        block_on(stream::iter([1, -2, 3]).map(Ok).forward(sink)).unwrap();
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), ["1", "-2", "3"]);
+    }
+}
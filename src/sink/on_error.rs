@@ -0,0 +1,117 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::on_error`](crate::SinkTools::on_error).
+#[pin_project]
+#[derive(Debug)]
+pub struct OnError<S, G, T> {
+    #[pin]
+    sink: S,
+    f: G,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, G, T> OnError<S, G, T>
+where
+    S: Sink<T>,
+    G: FnMut(&S::Error),
+{
+    pub(crate) fn new(sink: S, f: G) -> Self {
+        OnError {
+            sink,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, G, T> Sink<T> for OnError<S, G, T>
+where
+    S: Sink<T>,
+    G: FnMut(&S::Error),
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        this.sink.poll_ready(ctx).map_err(|e| inspect(this.f, e))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.sink.start_send(item).map_err(|e| inspect(this.f, e))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        this.sink.poll_flush(ctx).map_err(|e| inspect(this.f, e))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        this.sink.poll_close(ctx).map_err(|e| inspect(this.f, e))
+    }
+}
+
+fn inspect<E, G>(f: &mut G, e: E) -> E
+where
+    G: FnMut(&E),
+{
+    f(&e);
+    e
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::executor::block_on;
+    use futures::{Sink, SinkExt};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    #[derive(Debug, Default)]
+    struct FailingClose {
+        received: Vec<u32>,
+    }
+
+    impl Sink<u32> for FailingClose {
+        type Error = &'static str;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: u32) -> Result<(), Self::Error> {
+            self.received.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Err("close failed"))
+        }
+    }
+
+    #[test]
+    fn errors_are_observed_and_propagated() {
+        let mut inner = FailingClose::default();
+        let mut observed = Vec::new();
+        let mut sink = (&mut inner).on_error(|&e| observed.push(e));
+        block_on(sink.send(3)).unwrap();
+        assert_eq!(block_on(sink.close()), Err("close failed"));
+        assert_eq!(observed, ["close failed"]);
+        assert_eq!(inner.received, [3]);
+    }
+}
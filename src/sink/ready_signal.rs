@@ -0,0 +1,153 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use futures::{stream::FusedStream, Sink, Stream};
+use pin_project::pin_project;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// Sink returned by [`SinkTools::ready_signal`](crate::SinkTools::ready_signal).
+#[pin_project]
+#[derive(Debug)]
+pub struct ReadySignal<S, T> {
+    #[pin]
+    sink: S,
+    signaler: Signaler,
+    phantom: PhantomData<fn(T)>,
+}
+
+/// Stream of readiness signals returned by
+/// [`SinkTools::ready_signal`](crate::SinkTools::ready_signal).
+pub struct ReadySignals(Arc<Mutex<Shared>>);
+
+#[derive(Debug, Default)]
+struct Shared {
+    signals: VecDeque<bool>,
+    waker: Option<Waker>,
+    done: bool,
+}
+
+struct Signaler(Arc<Mutex<Shared>>);
+
+impl<S, T> ReadySignal<S, T>
+where
+    S: Sink<T>,
+{
+    pub(crate) fn new(sink: S) -> (Self, ReadySignals) {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let sink = ReadySignal {
+            sink,
+            signaler: Signaler(shared.clone()),
+            phantom: PhantomData,
+        };
+        (sink, ReadySignals(shared))
+    }
+}
+
+impl<S, T> Sink<T> for ReadySignal<S, T>
+where
+    S: Sink<T>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let res = this.sink.poll_ready(ctx);
+        this.signaler.send(res.is_ready());
+        res
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.project().sink.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+impl Signaler {
+    fn send(&self, signal: bool) {
+        if Arc::strong_count(&self.0) == 1 {
+            return;
+        }
+        let mut shared = self.0.lock().unwrap();
+        shared.signals.push_back(signal);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Debug for Signaler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Signaler")
+    }
+}
+
+impl Drop for Signaler {
+    fn drop(&mut self) {
+        let mut shared = self.0.lock().unwrap();
+        shared.done = true;
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Debug for ReadySignals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ReadySignals")
+    }
+}
+
+impl Stream for ReadySignals {
+    type Item = bool;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<bool>> {
+        let mut shared = self.0.lock().unwrap();
+        if let Some(signal) = shared.signals.pop_front() {
+            Poll::Ready(Some(signal))
+        } else if shared.done {
+            Poll::Ready(None)
+        } else {
+            shared.waker = Some(ctx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl FusedStream for ReadySignals {
+    fn is_terminated(&self) -> bool {
+        let shared = self.0.lock().unwrap();
+        shared.done && shared.signals.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::{channel::mpsc, executor::block_on, future, stream, StreamExt};
+
+    #[test]
+    fn pending_readiness_is_signaled() {
+        let (sender, receiver) = mpsc::channel(0);
+        let (sink, signals) = sender.ready_signal();
+        let send = stream::iter(0..5).map(Ok).forward(sink);
+        let (res, items) = block_on(future::join(send, receiver.collect::<Vec<_>>()));
+        res.unwrap();
+        assert_eq!(items, [0, 1, 2, 3, 4]);
+        let signals = block_on(signals.collect::<Vec<_>>());
+        assert!(signals.contains(&false));
+        assert!(signals.contains(&true));
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::retry`](crate::SinkTools::retry).
+#[pin_project]
+#[derive(Debug)]
+pub struct Retry<S, T> {
+    #[pin]
+    sink: S,
+    max: usize,
+    failures: usize,
+    buffer: Option<T>,
+}
+
+impl<S, T> Retry<S, T>
+where
+    S: Sink<T>,
+    T: Clone,
+{
+    pub(crate) fn new(sink: S, max: usize) -> Self {
+        Retry {
+            sink,
+            max,
+            failures: 0,
+            buffer: None,
+        }
+    }
+}
+
+impl<S, T> Sink<T> for Retry<S, T>
+where
+    S: Sink<T>,
+    T: Clone,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        while let Some(item) = this.buffer {
+            let res = match ready!(this.sink.as_mut().poll_ready(ctx)) {
+                Ok(()) => this.sink.as_mut().start_send(item.clone()),
+                Err(e) => Err(e),
+            };
+            match res {
+                Ok(()) => {
+                    *this.buffer = None;
+                    *this.failures = 0;
+                }
+                Err(_) if *this.failures < *this.max => *this.failures += 1,
+                Err(e) => {
+                    *this.buffer = None;
+                    *this.failures = 0;
+                    return Poll::Ready(Err(e));
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        *this.buffer = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::executor::block_on;
+    use futures::{Sink, SinkExt};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    #[derive(Debug, Default)]
+    struct Flaky {
+        failures: usize,
+        received: Vec<u32>,
+    }
+
+    impl Sink<u32> for Flaky {
+        type Error = usize;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), usize>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: u32) -> Result<(), usize> {
+            if self.failures > 0 {
+                self.failures -= 1;
+                Err(self.failures)
+            } else {
+                self.received.push(item);
+                Ok(())
+            }
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), usize>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), usize>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn send_succeeds_after_transient_failures() {
+        let mut flaky = Flaky {
+            failures: 2,
+            ..Flaky::default()
+        };
+        block_on((&mut flaky).retry(2).send(7)).unwrap();
+        assert_eq!(flaky.received, [7]);
+    }
+
+    #[test]
+    fn error_is_returned_once_retries_are_exhausted() {
+        let mut flaky = Flaky {
+            failures: 3,
+            ..Flaky::default()
+        };
+        assert_eq!(block_on((&mut flaky).retry(2).send(7)), Err(0));
+        assert!(flaky.received.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+
+/// Sink returned by [`scatter_weighted`](crate::sink::scatter_weighted).
+pub struct ScatterWeighted<Sk, T> {
+    sinks: Vec<WeightedSink<Sk>>,
+    total_weight: usize,
+    buffer: Option<(usize, T)>,
+}
+
+impl<Sk, T> ScatterWeighted<Sk, T>
+where
+    Sk: Sink<T> + Unpin,
+{
+    pub(crate) fn new(sinks: Vec<(Sk, usize)>) -> Self {
+        let sinks = sinks
+            .into_iter()
+            .map(|(sink, weight)| WeightedSink {
+                sink,
+                weight,
+                current: 0,
+                closed: false,
+            })
+            .collect::<Vec<_>>();
+        let total_weight = sinks.iter().map(|s| s.weight).sum();
+        ScatterWeighted {
+            sinks,
+            total_weight,
+            buffer: None,
+        }
+    }
+
+    fn pick(&mut self) -> Option<usize> {
+        let mut best = None;
+        for (i, s) in self.sinks.iter_mut().enumerate() {
+            if s.weight == 0 {
+                continue;
+            }
+            s.current += s.weight as isize;
+            if !matches!(best, Some((_, current)) if current >= s.current) {
+                best = Some((i, s.current));
+            }
+        }
+        let (i, _) = best?;
+        self.sinks[i].current -= self.total_weight as isize;
+        Some(i)
+    }
+}
+
+impl<Sk, T> Debug for ScatterWeighted<Sk, T>
+where
+    Sk: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScatterWeighted")
+            .field("sinks", &self.sinks)
+            .finish()
+    }
+}
+
+impl<Sk, T> Unpin for ScatterWeighted<Sk, T> {}
+
+impl<Sk, T> Sink<T> for ScatterWeighted<Sk, T>
+where
+    Sk: Sink<T> + Unpin,
+{
+    type Error = Sk::Error;
+
+    fn poll_ready(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let this = &mut *self;
+        if let Some((i, _)) = &this.buffer {
+            let mut sink = Pin::new(&mut this.sinks[*i].sink);
+            ready!(sink.as_mut().poll_ready(ctx)?);
+            if let Some((_, item)) = this.buffer.take() {
+                sink.start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        assert!(self.buffer.is_none());
+        if let Some(i) = self.pick() {
+            self.buffer = Some((i, item));
+        }
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let mut pending = false;
+        for s in &mut self.sinks {
+            pending |= Pin::new(&mut s.sink).poll_flush(ctx)?.is_pending();
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let mut pending = false;
+        for s in self.sinks.iter_mut().filter(|s| !s.closed) {
+            match Pin::new(&mut s.sink).poll_close(ctx)? {
+                Poll::Ready(()) => s.closed = true,
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct WeightedSink<Sk> {
+    sink: Sk,
+    weight: usize,
+    current: isize,
+    closed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sink::scatter_weighted;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::stream;
+    use futures::StreamExt;
+
+    #[test]
+    fn items_are_distributed_by_weight() {
+        let (light_sender, light_receiver) = mpsc::unbounded();
+        let (heavy_sender, heavy_receiver) = mpsc::unbounded();
+        let res = stream::iter(0..8)
+            .map(Ok)
+            .forward(scatter_weighted(vec![(light_sender, 1), (heavy_sender, 3)]));
+        block_on(res).unwrap();
+        let light = block_on(light_receiver.collect::<Vec<_>>());
+        let heavy = block_on(heavy_receiver.collect::<Vec<_>>());
+        assert_eq!(light, [1, 5]);
+        assert_eq!(heavy, [0, 2, 3, 4, 6, 7]);
+    }
+}
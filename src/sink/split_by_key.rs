@@ -0,0 +1,161 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use futures::Sink;
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::Hash,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+/// Sink returned by [`split_by_key`](crate::sink::split_by_key).
+pub struct SplitByKey<K, Sk, G, M, T> {
+    indices: HashMap<K, usize>,
+    sinks: Vec<KeyedSink<Sk>>,
+    key_of: G,
+    make_sink: M,
+    buffer: Option<(usize, T)>,
+}
+
+impl<K, Sk, G, M, T> SplitByKey<K, Sk, G, M, T>
+where
+    K: Eq + Hash,
+    Sk: Sink<T> + Unpin,
+    G: FnMut(&T) -> K,
+    M: FnMut(&K) -> Sk,
+{
+    pub(crate) fn new(key_of: G, make_sink: M) -> Self {
+        SplitByKey {
+            indices: HashMap::new(),
+            sinks: Vec::new(),
+            key_of,
+            make_sink,
+            buffer: None,
+        }
+    }
+}
+
+impl<K, Sk, G, M, T> Debug for SplitByKey<K, Sk, G, M, T>
+where
+    Sk: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitByKey")
+            .field("sinks", &self.sinks)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, Sk, G, M, T> Unpin for SplitByKey<K, Sk, G, M, T> {}
+
+impl<K, Sk, G, M, T> Sink<T> for SplitByKey<K, Sk, G, M, T>
+where
+    K: Eq + Hash,
+    Sk: Sink<T> + Unpin,
+    G: FnMut(&T) -> K,
+    M: FnMut(&K) -> Sk,
+{
+    type Error = Sk::Error;
+
+    fn poll_ready(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let this = &mut *self;
+        if let Some((i, _)) = &this.buffer {
+            let mut sink = Pin::new(&mut this.sinks[*i].sink);
+            ready!(sink.as_mut().poll_ready(ctx)?);
+            if let Some((_, item)) = this.buffer.take() {
+                sink.start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = &mut *self;
+        assert!(this.buffer.is_none());
+        let key = (this.key_of)(&item);
+        let sinks = &mut this.sinks;
+        let make_sink = &mut this.make_sink;
+        let i = *this.indices.entry(key).or_insert_with_key(|key| {
+            sinks.push(KeyedSink {
+                sink: make_sink(key),
+                closed: false,
+            });
+            sinks.len() - 1
+        });
+        this.buffer = Some((i, item));
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let mut pending = false;
+        for s in &mut self.sinks {
+            pending |= Pin::new(&mut s.sink).poll_flush(ctx)?.is_pending();
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let mut pending = false;
+        for s in self.sinks.iter_mut().filter(|s| !s.closed) {
+            match Pin::new(&mut s.sink).poll_close(ctx)? {
+                Poll::Ready(()) => s.closed = true,
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct KeyedSink<Sk> {
+    sink: Sk,
+    closed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sink::split_by_key;
+    use futures::{channel::mpsc, executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn items_are_routed_to_lazily_created_sinks() {
+        let mut receivers = Vec::new();
+        let sink = split_by_key(
+            |n: &u32| n % 3,
+            |&key| {
+                let (sender, receiver) = mpsc::unbounded();
+                receivers.push((key, receiver));
+                sender
+            },
+        );
+        block_on(stream::iter(0..9).map(Ok).forward(sink)).unwrap();
+        let shards = receivers
+            .into_iter()
+            .map(|(key, receiver)| (key, block_on(receiver.collect::<Vec<_>>())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shards,
+            [(0, vec![0, 3, 6]), (1, vec![1, 4, 7]), (2, vec![2, 5, 8])]
+        );
+    }
+}
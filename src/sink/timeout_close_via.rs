@@ -0,0 +1,181 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, StreamExt},
+    Sink, Stream,
+};
+use pin_project::pin_project;
+
+/// Error returned by [`TimeoutCloseVia`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimeoutCloseError<E> {
+    /// The underlying sink did not close before the timeout elapsed
+    Elapsed,
+    /// The underlying sink failed
+    Sink(E),
+}
+
+impl<E: Display> Display for TimeoutCloseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeoutCloseError::Elapsed => {
+                f.write_str("Sink did not close before the timeout elapsed")
+            }
+            TimeoutCloseError::Sink(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for TimeoutCloseError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimeoutCloseError::Elapsed => None,
+            TimeoutCloseError::Sink(e) => Some(e),
+        }
+    }
+}
+
+/// Sink returned by [`SinkTools::timeout_close_via`](crate::SinkTools::timeout_close_via).
+#[pin_project]
+#[derive(Debug)]
+pub struct TimeoutCloseVia<S, Tk, T> {
+    #[pin]
+    sink: S,
+    #[pin]
+    ticks: Fuse<Tk>,
+    elapsed_ticks: usize,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, Tk, T> TimeoutCloseVia<S, Tk, T>
+where
+    S: Sink<T>,
+    Tk: Stream,
+{
+    pub(crate) fn new(sink: S, ticks: Tk) -> Self {
+        TimeoutCloseVia {
+            sink,
+            ticks: ticks.fuse(),
+            elapsed_ticks: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, Tk, T> Sink<T> for TimeoutCloseVia<S, Tk, T>
+where
+    S: Sink<T>,
+    Tk: Stream,
+{
+    type Error = TimeoutCloseError<S::Error>;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project()
+            .sink
+            .poll_ready(ctx)
+            .map_err(TimeoutCloseError::Sink)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.project()
+            .sink
+            .start_send(item)
+            .map_err(TimeoutCloseError::Sink)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project()
+            .sink
+            .poll_flush(ctx)
+            .map_err(TimeoutCloseError::Sink)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if let Poll::Ready(res) = this.sink.poll_close(ctx) {
+            return Poll::Ready(res.map_err(TimeoutCloseError::Sink));
+        }
+        while let Poll::Ready(Some(_)) = this.ticks.as_mut().poll_next(ctx) {
+            *this.elapsed_ticks += 1;
+            if *this.elapsed_ticks > 1 {
+                *this.elapsed_ticks = 0;
+                return Poll::Ready(Err(TimeoutCloseError::Elapsed));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{sink::TimeoutCloseError, SinkTools};
+    use futures::{channel::mpsc, executor::block_on, poll, Sink, SinkExt};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    #[derive(Debug, Default)]
+    struct StuckClose {
+        received: Vec<u32>,
+    }
+
+    impl Sink<u32> for StuckClose {
+        type Error = ();
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: u32) -> Result<(), ()> {
+            self.received.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn stuck_close_times_out_after_two_ticks() {
+        let (tick_sender, ticks) = mpsc::unbounded();
+        let mut inner = StuckClose::default();
+        let mut sink = (&mut inner).timeout_close_via(ticks);
+        block_on(async {
+            sink.send(1).await.unwrap();
+            let mut close = sink.close();
+            assert!(poll!(&mut close).is_pending());
+            tick_sender.unbounded_send(()).unwrap();
+            assert!(poll!(&mut close).is_pending());
+            tick_sender.unbounded_send(()).unwrap();
+            assert_eq!(
+                poll!(&mut close),
+                Poll::Ready(Err(TimeoutCloseError::Elapsed))
+            );
+        });
+        assert_eq!(inner.received, [1]);
+    }
+
+    #[test]
+    fn prompt_close_succeeds() {
+        let (sender, _receiver) = mpsc::unbounded::<u32>();
+        let (_tick_sender, ticks) = mpsc::unbounded::<()>();
+        let mut sink = sender.timeout_close_via(ticks);
+        assert_eq!(block_on(sink.close()), Ok(()));
+    }
+}
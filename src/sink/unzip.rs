@@ -0,0 +1,132 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::sink::close_once;
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`unzip`](crate::sink::unzip).
+#[pin_project]
+#[derive(Debug)]
+pub struct Unzip<SA, SB, A, B> {
+    #[pin]
+    left_sink: SA,
+    #[pin]
+    right_sink: SB,
+    left_buffer: Option<A>,
+    right_buffer: Option<B>,
+    left_closed: bool,
+    right_closed: bool,
+}
+
+impl<SA, SB, A, B> Unzip<SA, SB, A, B>
+where
+    SA: Sink<A>,
+    SB: Sink<B, Error = SA::Error>,
+{
+    pub(crate) fn new(left_sink: SA, right_sink: SB) -> Self {
+        Unzip {
+            left_sink,
+            right_sink,
+            left_buffer: None,
+            right_buffer: None,
+            left_closed: false,
+            right_closed: false,
+        }
+    }
+}
+
+impl<SA, SB, A, B> Sink<(A, B)> for Unzip<SA, SB, A, B>
+where
+    SA: Sink<A>,
+    SB: Sink<B, Error = SA::Error>,
+{
+    type Error = SA::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if this.left_buffer.is_some() {
+            if let Poll::Ready(()) = this.left_sink.as_mut().poll_ready(ctx)? {
+                if let Some(item) = this.left_buffer.take() {
+                    this.left_sink.as_mut().start_send(item)?;
+                }
+            }
+        }
+        if this.right_buffer.is_some() {
+            if let Poll::Ready(()) = this.right_sink.as_mut().poll_ready(ctx)? {
+                if let Some(item) = this.right_buffer.take() {
+                    this.right_sink.as_mut().start_send(item)?;
+                }
+            }
+        }
+        if this.left_buffer.is_none() && this.right_buffer.is_none() {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, (a, b): (A, B)) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.left_buffer.is_none() && this.right_buffer.is_none());
+        *this.left_buffer = Some(a);
+        *this.right_buffer = Some(b);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        let buffer_res = self.as_mut().poll_ready(ctx)?;
+        let this = self.project();
+        let left_res = this.left_sink.poll_flush(ctx);
+        let right_res = this.right_sink.poll_flush(ctx);
+        match (buffer_res, left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        let this = self.project();
+        let left_res = close_once(this.left_sink, this.left_closed, ctx);
+        let right_res = close_once(this.right_sink, this.right_closed, ctx);
+        match (left_res?, right_res?) {
+            (Poll::Ready(_), Poll::Ready(_)) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sink::unzip;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn pairs_are_split_between_sinks() {
+        let (left_sender, left_receiver) = mpsc::channel(0);
+        let (right_sender, right_receiver) = mpsc::channel(0);
+        let pairs = stream::iter(0..5).map(|n| Ok((n, n * 10)));
+        let (sent, left, right) = block_on(async {
+            futures::join!(
+                pairs.forward(unzip(left_sender, right_sender)),
+                left_receiver.collect::<Vec<_>>(),
+                right_receiver.collect::<Vec<_>>(),
+            )
+        });
+        sent.unwrap();
+        assert_eq!(left, [0, 1, 2, 3, 4]);
+        assert_eq!(right, [0, 10, 20, 30, 40]);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::collections::VecDeque;
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::with_fan`](crate::SinkTools::with_fan).
+#[pin_project]
+#[derive(Debug)]
+pub struct WithFan<S, G, T, U> {
+    #[pin]
+    sink: S,
+    f: G,
+    queue: VecDeque<U>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, G, T, I> WithFan<S, G, T, I::Item>
+where
+    S: Sink<I::Item>,
+    G: FnMut(T) -> I,
+    I: IntoIterator,
+{
+    pub(crate) fn new(sink: S, f: G) -> Self {
+        WithFan {
+            sink,
+            f,
+            queue: VecDeque::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, G, T, I> Sink<T> for WithFan<S, G, T, I::Item>
+where
+    S: Sink<I::Item>,
+    G: FnMut(T) -> I,
+    I: IntoIterator,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        while !this.queue.is_empty() {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.queue.pop_front() {
+                this.sink.as_mut().start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.queue.is_empty());
+        this.queue.extend((this.f)(item));
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{stream, SinkExt, StreamExt};
+
+    #[test]
+    fn items_are_fanned_out() {
+        let (sender, receiver) = mpsc::channel(0);
+        let sink = sender.with_fan(|n: usize| std::iter::repeat(n).take(n));
+        let sent = stream::iter([0, 1, 2, 3]).map(Ok).forward(sink);
+        let (sent, received) =
+            block_on(async { futures::join!(sent, receiver.collect::<Vec<_>>()) });
+        sent.unwrap();
+        assert_eq!(received, [1, 2, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn pending_items_are_sent_on_close() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut sink = sender.with_fan(|n: u32| [n, n * 10]);
+        block_on(async {
+            sink.feed(1).await.unwrap();
+            sink.close().await.unwrap();
+        });
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [1, 10]);
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`SinkTools::with_filter_map`](crate::SinkTools::with_filter_map).
+#[pin_project]
+#[derive(Debug)]
+pub struct WithFilterMap<S, G, T, U> {
+    #[pin]
+    sink: S,
+    f: G,
+    buffer: Option<U>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<S, G, T, U> WithFilterMap<S, G, T, U>
+where
+    S: Sink<U>,
+    G: FnMut(T) -> Option<U>,
+{
+    pub(crate) fn new(sink: S, f: G) -> Self {
+        WithFilterMap {
+            sink,
+            f,
+            buffer: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, G, T, U> Sink<T> for WithFilterMap<S, G, T, U>
+where
+    S: Sink<U>,
+    G: FnMut(T) -> Option<U>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if this.buffer.is_some() {
+            ready!(this.sink.as_mut().poll_ready(ctx)?);
+            if let Some(item) = this.buffer.take() {
+                this.sink.start_send(item)?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.project();
+        assert!(this.buffer.is_none());
+        *this.buffer = (this.f)(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_flush(ctx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_ready(ctx)?);
+        self.project().sink.poll_close(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SinkTools;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::stream;
+    use futures::StreamExt;
+
+    #[test]
+    fn odds_are_dropped_and_evens_mapped() {
+        let (sender, receiver) = mpsc::unbounded();
+        let sink = sender.with_filter_map(|n: u32| (n % 2 == 0).then(|| n.to_string()));
+        block_on(stream::iter(0..6).map(Ok).forward(sink)).unwrap();
+        let received = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(received, ["0", "2", "4"]);
+    }
+}
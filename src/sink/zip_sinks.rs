@@ -0,0 +1,131 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Sink;
+use pin_project::pin_project;
+
+/// Sink returned by [`zip_sinks`](crate::sink::zip_sinks).
+#[pin_project]
+#[derive(Debug)]
+pub struct ZipSinks<AS, BS, E> {
+    #[pin]
+    a: AS,
+    #[pin]
+    b: BS,
+    a_closed: bool,
+    b_closed: bool,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<AS, BS, E> ZipSinks<AS, BS, E> {
+    pub(crate) fn new(a: AS, b: BS) -> Self {
+        ZipSinks {
+            a,
+            b,
+            a_closed: false,
+            b_closed: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<AS, BS, E, A, B> Sink<(A, B)> for ZipSinks<AS, BS, E>
+where
+    AS: Sink<A>,
+    BS: Sink<B>,
+    AS::Error: Into<E>,
+    BS::Error: Into<E>,
+{
+    type Error = E;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let a_res = this.a.poll_ready(ctx).map_err(Into::into);
+        let b_res = this.b.poll_ready(ctx).map_err(Into::into);
+        both(a_res, b_res)
+    }
+
+    fn start_send(self: Pin<&mut Self>, (a, b): (A, B)) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.a.start_send(a).map_err(Into::into)?;
+        this.b.start_send(b).map_err(Into::into)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let a_res = this.a.poll_flush(ctx).map_err(Into::into);
+        let b_res = this.b.poll_flush(ctx).map_err(Into::into);
+        both(a_res, b_res)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let a_res = if *this.a_closed {
+            Poll::Ready(Ok(()))
+        } else {
+            let res = this.a.poll_close(ctx).map_err(Into::into);
+            *this.a_closed = matches!(res, Poll::Ready(Ok(())));
+            res
+        };
+        let b_res = if *this.b_closed {
+            Poll::Ready(Ok(()))
+        } else {
+            let res = this.b.poll_close(ctx).map_err(Into::into);
+            *this.b_closed = matches!(res, Poll::Ready(Ok(())));
+            res
+        };
+        both(a_res, b_res)
+    }
+}
+
+fn both<E>(a: Poll<Result<(), E>>, b: Poll<Result<(), E>>) -> Poll<Result<(), E>> {
+    match (a?, b?) {
+        (Poll::Ready(()), Poll::Ready(())) => Poll::Ready(Ok(())),
+        _ => Poll::Pending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sink::zip_sinks;
+    use futures::{channel::mpsc, executor::block_on, stream, SinkExt, StreamExt};
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Send,
+        Drain,
+    }
+
+    impl From<mpsc::SendError> for Error {
+        fn from(_: mpsc::SendError) -> Self {
+            Error::Send
+        }
+    }
+
+    impl From<std::convert::Infallible> for Error {
+        fn from(_: std::convert::Infallible) -> Self {
+            Error::Drain
+        }
+    }
+
+    #[test]
+    fn paired_items_are_split_between_sinks() {
+        let (sender, receiver) = mpsc::unbounded();
+        let sink = zip_sinks::<_, _, Error>(sender, futures::sink::drain());
+        let pairs = stream::iter([(0, "a"), (1, "b"), (2, "c")]).map(Ok);
+        block_on(pairs.forward(sink)).unwrap();
+        assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2]);
+    }
+
+    #[test]
+    fn errors_are_converted() {
+        let (sender, receiver) = mpsc::unbounded::<i32>();
+        drop(receiver);
+        let mut sink = zip_sinks::<_, _, Error>(sender, futures::sink::drain());
+        assert_eq!(block_on(sink.send((0, ()))), Err(Error::Send));
+    }
+}
@@ -2,20 +2,848 @@
 
 //! Tools for streams
 
-use futures::Stream;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::future::Future;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::AtomicBool;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "std")]
+use either::Either;
+use futures::{Sink, Stream, TryStream};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
+pub use abort_on::AbortOn;
+#[cfg(feature = "alloc")]
+pub use batch_ready::{BatchReady, ChunksUntilIdle};
+#[cfg(feature = "alloc")]
+pub use buffer_until::BufferUntil;
+#[cfg(feature = "alloc")]
+pub use buffered_latest::BufferedLatest;
+#[cfg(feature = "alloc")]
+pub use chunks_of::ChunksOf;
+pub use collect_latest_into::CollectLatestInto;
+pub use debounce::Debounce;
+pub use dedup_by_key::DedupByKey;
+#[cfg(feature = "alloc")]
+pub use delay_items_via::DelayItemsVia;
+#[cfg(feature = "std")]
+pub use distinct::Distinct;
+#[cfg(feature = "alloc")]
+pub use drain_ready::DrainReady;
+pub use enumerate_from::{CountLatest, EnumerateFrom};
+pub use flatten_latest::FlattenLatest;
+#[cfg(feature = "alloc")]
+pub use forward_all::ForwardAll;
+pub use fuse_latest::FuseLatest;
+#[cfg(feature = "alloc")]
+pub use group_by_key::GroupByKey;
+pub use hold::Hold;
+pub use index_with::IndexWith;
+pub use inspect_latest::InspectLatest;
+pub use interleave::Interleave;
+pub use last::Last;
+pub use latest_or::LatestOr;
+pub use map_while_latest::MapWhileLatest;
+pub use merge_latest::MergeLatest;
+pub use next_or_yield::NextOrYield;
+#[cfg(feature = "alloc")]
+pub use observe_terminated::ObserveTerminated;
+pub use on_empty::OnEmpty;
+pub use pace_by::PaceBy;
+#[cfg(feature = "std")]
+pub use partition::{
+    Partition, PartitionBy, PartitionByFalse, PartitionByTrue, PartitionLeft, PartitionRight,
+};
+pub use peekable_latest::{Peek, PeekableLatest};
+#[cfg(feature = "alloc")]
+pub use rate_limit::{Overflow, RateLimit};
+pub use repeat_latest::RepeatLatest;
+pub use repeat_with_yield::RepeatWithYield;
+pub use retry_stream::RetryStream;
+pub use sample_both::SampleBoth;
+pub use scan_latest::ScanLatest;
+pub use scan_with_yield::ScanWithYield;
+pub use split_first::SplitFirst;
+pub use start_with::StartWith;
+pub use step_by::StepBy;
+pub use take_until_done::TakeUntilDone;
+pub use take_while_latest::TakeWhileLatest;
+pub use tee::Tee;
+pub use throttle_first::ThrottleFirst;
+pub use throttle_latest::ThrottleLatest;
+pub use timeout_via::{Elapsed, OnElapsed, TimeoutVia};
+pub use try_zip_latest_with::TryZipLatestWith;
+#[cfg(feature = "alloc")]
+pub use windows::Windows;
 pub use zip_latest::ZipLatest;
-pub use zip_latest_all::ZipLatestAll;
+pub use zip_latest3::ZipLatest3;
+#[cfg(feature = "alloc")]
+pub use zip_latest_all::{FirstThenDeltas, ZipLatestAll, ZipLatestAllArray, ZipLatestAllIndexed};
+#[cfg(feature = "alloc")]
+pub use zip_latest_backlog::ZipLatestBacklog;
+pub use zip_latest_changes::{Changed, ZipLatestChanges};
+pub use zip_latest_filter_map::ZipLatestFilterMap;
+pub use zip_latest_finalize::{Side, ZipLatestFinalize};
+#[cfg(feature = "std")]
+pub use zip_latest_labeled::ZipLatestLabeled;
+#[cfg(feature = "alloc")]
+pub use zip_latest_shared::ZipLatestShared;
 pub use zip_latest_with::ZipLatestWith;
-pub use zip_latest_with_all::ZipLatestWithAll;
+pub use zip_latest_with3::ZipLatestWith3;
+#[cfg(feature = "alloc")]
+pub use zip_latest_with_all::{BoxCombine, ZipEvent, ZipLatestWithAll};
+#[cfg(feature = "std")]
+pub use zip_latest_with_all::{CombineHandle, SwappableZipLatestWithAll};
+#[cfg(feature = "std")]
+pub use zip_latest_with_all_dynamic::{ZipLatestWithAllDynamic, ZipLatestWithAllHandle};
+#[cfg(feature = "alloc")]
+pub use zip_latest_with_all_refresh::ZipLatestWithAllRefresh;
+#[cfg(feature = "alloc")]
+pub use zip_latest_with_all_resilient::ZipLatestWithAllResilient;
+#[cfg(feature = "std")]
+pub use zip_latest_with_all_sparse_map::ZipLatestWithAllSparseMap;
+pub use zip_latest_with_cloned::ZipLatestWithCloned;
+pub use zip_latest_with_drained::ZipLatestWithDrained;
 
+mod abort_on;
+#[cfg(feature = "alloc")]
+mod batch_ready;
+#[cfg(feature = "alloc")]
+mod buffer_until;
+#[cfg(feature = "alloc")]
+mod buffered_latest;
+#[cfg(feature = "alloc")]
+mod chunks_of;
+mod collect_latest_into;
+mod debounce;
+mod dedup_by_key;
+#[cfg(feature = "alloc")]
+mod delay_items_via;
+#[cfg(feature = "std")]
+mod distinct;
+#[cfg(feature = "alloc")]
+mod drain_ready;
+mod enumerate_from;
+mod flatten_latest;
+#[cfg(feature = "alloc")]
+mod forward_all;
+mod fuse;
+mod fuse_latest;
+#[cfg(feature = "alloc")]
+mod group_by_key;
+mod hold;
+mod index_with;
+mod inspect_latest;
+mod interleave;
+mod last;
+mod latest_or;
+mod map_while_latest;
+mod merge_latest;
+mod next_or_yield;
+#[cfg(feature = "alloc")]
+mod observe_terminated;
+mod on_empty;
+mod pace_by;
+#[cfg(feature = "std")]
+mod partition;
+mod peekable_latest;
+#[cfg(feature = "alloc")]
+mod rate_limit;
+mod repeat_latest;
+mod repeat_with_yield;
+mod retry_stream;
+mod sample_both;
+mod scan_latest;
+mod scan_with_yield;
+mod split_first;
+mod start_with;
+mod step_by;
+mod take_until_done;
+mod take_while_latest;
+mod tee;
+mod throttle_first;
+mod throttle_latest;
+mod timeout_via;
+mod try_zip_latest_with;
+#[cfg(feature = "alloc")]
+mod windows;
 mod zip_latest;
+mod zip_latest3;
+#[cfg(feature = "alloc")]
 mod zip_latest_all;
+#[cfg(feature = "alloc")]
+mod zip_latest_backlog;
+mod zip_latest_changes;
+mod zip_latest_filter_map;
+mod zip_latest_finalize;
+#[cfg(feature = "std")]
+mod zip_latest_labeled;
+#[cfg(feature = "alloc")]
+mod zip_latest_shared;
 mod zip_latest_with;
+mod zip_latest_with3;
+#[cfg(feature = "alloc")]
 mod zip_latest_with_all;
+#[cfg(feature = "std")]
+mod zip_latest_with_all_dynamic;
+#[cfg(feature = "alloc")]
+mod zip_latest_with_all_refresh;
+#[cfg(feature = "alloc")]
+mod zip_latest_with_all_resilient;
+#[cfg(feature = "std")]
+mod zip_latest_with_all_sparse_map;
+mod zip_latest_with_cloned;
+mod zip_latest_with_drained;
 
 /// Extension trait for [`Stream`](futures::Stream).
 pub trait StreamTools: Stream {
+    /// Yields the items of `self` until `signal` completes, recording why the stream ended
+    ///
+    /// This behaves like [`take_until_done`](StreamTools::take_until_done) but
+    /// [`AbortOn::was_aborted`] tells whether the returned stream ended because `signal` completed
+    /// rather than because `self` was exhausted.
+    fn abort_on<Fut>(self, signal: Fut) -> AbortOn<Self, Fut>
+    where
+        Self: Sized,
+        Fut: Future,
+    {
+        AbortOn::new(self, signal)
+    }
+
+    /// Yields batches of all the items `self` can produce without waiting
+    ///
+    /// Each time the returned stream is polled, `self` is polled until it is not ready, ends, or
+    /// produced 128 items, and the items produced in the meantime are yielded together. Batches are
+    /// never empty. This is like [`StreamExt::ready_chunks`](futures::StreamExt::ready_chunks)
+    /// without having to pick a capacity.
+    #[cfg(feature = "alloc")]
+    fn batch_ready(self) -> BatchReady<Self>
+    where
+        Self: Sized,
+    {
+        BatchReady::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Collects the items of `self` and yields them in a batch each time `trigger` yields
+    ///
+    /// If `skip_empty` is `true`, no batch is yielded when `trigger` yields while no item was
+    /// collected since the previous batch. Otherwise, an empty batch is yielded. When `self` ends,
+    /// the items collected since the previous batch are yielded if there are any.
+    ///
+    /// Visually, this gives:
+    /// ```text
+    /// ---0--1--------2----------------|-> self
+    /// ----------x--------x-----x--------> trigger
+    /// ----------[0,1]----[2]---[]-----|-> self.buffer_until(trigger, false)
+    /// ```
+    fn buffer_until<G>(self, trigger: G, skip_empty: bool) -> BufferUntil<Self, G>
+    where
+        Self: Sized,
+        G: Stream,
+    {
+        BufferUntil::new(self, trigger, skip_empty)
+    }
+
+    /// Eagerly polls `self` to keep up to `n` of its items queued
+    ///
+    /// Each time the returned stream is polled, `self` is polled until `n` items are queued or it
+    /// is not ready, and the oldest queued item is yielded. This smooths out bursty streams. When
+    /// `self` ends, the queued items are yielded and the returned stream ends. An `n` of 0 is
+    /// treated as 1.
+    #[cfg(feature = "alloc")]
+    fn buffered_latest(self, n: usize) -> BufferedLatest<Self>
+    where
+        Self: Sized,
+    {
+        BufferedLatest::new(self, n)
+    }
+
+    /// Yields the items of `self` in batches of `n` items
+    ///
+    /// A batch is yielded as soon as `n` items have been received, even if more items are
+    /// immediately available. When `self` ends, the remaining items are yielded in a shorter batch
+    /// if there are any.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks_of(self, n: usize) -> ChunksOf<Self>
+    where
+        Self: Sized,
+    {
+        ChunksOf::new(self, n)
+    }
+
+    /// Folds every item of `self` into `init` using `f`
+    ///
+    /// The returned future resolves to the accumulator once `self` ends. Unlike
+    /// [`StreamExt::fold`](futures::StreamExt::fold), `f` is synchronous and updates the
+    /// accumulator in place.
+    fn collect_latest_into<St, F>(self, init: St, f: F) -> CollectLatestInto<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item),
+    {
+        CollectLatestInto::new(self, init, f)
+    }
+
+    /// Alias of [`batch_ready`](StreamTools::batch_ready)
+    ///
+    /// A batch is yielded as soon as `self` is idle, so each burst of items is yielded as a single
+    /// batch. This is only kept for convenience; `batch_ready` is the canonical name.
+    #[cfg(feature = "alloc")]
+    fn chunks_until_idle(self) -> ChunksUntilIdle<Self>
+    where
+        Self: Sized,
+    {
+        self.batch_ready()
+    }
+
+    /// Pairs each item of `self` with the number of items yielded so far, including itself
+    ///
+    /// This is a thin alias of [`enumerate_from(1)`](StreamTools::enumerate_from), so the first
+    /// item is paired with 1. It returns the same stream and adds no behavior of its own.
+    fn count_latest(self) -> CountLatest<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateFrom::new(self, 1)
+    }
+
+    /// Yields the latest item of `self` each time `quiet` yields
+    ///
+    /// Items of `self` replace the pending item, which is yielded the next time `quiet` yields and
+    /// then cleared. Ticks of `quiet` while no item is pending are ignored. When `self` ends, the
+    /// pending item is yielded if there is one and the returned stream ends. The returned stream
+    /// also ends when `quiet` ends.
+    fn debounce<Q>(self, quiet: Q) -> Debounce<Self, Q>
+    where
+        Self: Sized,
+        Q: Stream,
+    {
+        Debounce::new(self, quiet)
+    }
+
+    /// Splits a stream in two according to `pred`
+    ///
+    /// Items for which `pred` returns `true` are yielded by the first returned stream while the
+    /// other items are yielded by the second one. This behaves like
+    /// [`partition`](StreamTools::partition) otherwise.
+    #[cfg(feature = "std")]
+    fn partition_by<P>(self, pred: P) -> PartitionBy<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        partition::partition_by(self, pred)
+    }
+
+    /// Drops consecutive items of `self` whose key is equal to the key of the previous item
+    ///
+    /// Only the key returned by `f` determines duplicates; the items themselves are yielded
+    /// unchanged.
+    fn dedup_by_key<K, F>(self, f: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, f)
+    }
+
+    /// Yields the items of `self` one per tick of `ticks`
+    ///
+    /// Unlike [`rate_limit`](StreamTools::rate_limit), no item is dropped: items of `self` are
+    /// buffered without bound until a tick releases them, so memory grows with the lag behind
+    /// `ticks`. Ticks received while no item is waiting are not saved for later.
+    ///
+    /// The returned stream ends when `self` ends and all buffered items have been released, or
+    /// when `ticks` ends.
+    #[cfg(feature = "alloc")]
+    fn delay_items_via<Tk>(self, ticks: Tk) -> DelayItemsVia<Self, Tk>
+    where
+        Self: Sized,
+        Tk: Stream,
+    {
+        DelayItemsVia::new(self, ticks)
+    }
+
+    /// Drops items of `self` equal to any item yielded before
+    ///
+    /// Unlike [`dedup_by_key`](StreamTools::dedup_by_key), duplicates need not be consecutive. A
+    /// clone of every yielded item is kept for the lifetime of the returned stream, so memory
+    /// grows without bound with the number of distinct items.
+    #[cfg(feature = "std")]
+    fn distinct(self) -> Distinct<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone + Eq + Hash,
+    {
+        Distinct::new(self)
+    }
+
+    /// Pairs each item of `self` with its index, starting at `start`
+    ///
+    /// This is like [`StreamExt::enumerate`](futures::StreamExt::enumerate) but with a custom first
+    /// index. The index saturates at `usize::MAX`, so all items past that point are paired with
+    /// `usize::MAX`.
+    fn enumerate_from(self, start: usize) -> EnumerateFrom<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateFrom::new(self, start)
+    }
+
+    /// Flattens a stream of streams, always yielding from the latest inner stream
+    ///
+    /// Each time `self` yields a new inner stream, the previous inner stream is dropped, even if it
+    /// had more items. The returned stream ends when `self` ends and the last inner stream ends.
+    fn flatten_latest(self) -> FlattenLatest<Self>
+    where
+        Self: Sized,
+        Self::Item: Stream,
+    {
+        FlattenLatest::new(self)
+    }
+
+    /// Sends clones of each item of `self` to all `sinks`
+    ///
+    /// The returned future completes once `self` ends and all sinks are closed. The first error
+    /// returned by any sink is returned right away.
+    #[cfg(feature = "alloc")]
+    fn forward_all<I>(self, sinks: I) -> ForwardAll<Self, I::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        I: IntoIterator,
+        I::Item: Sink<Self::Item> + Unpin,
+    {
+        ForwardAll::new(self, sinks)
+    }
+
+    /// Fuses `self` and keeps a clone of the last item yielded
+    ///
+    /// The returned stream can be polled after it ends, and the last item remains available
+    /// through [`FuseLatest::last`].
+    fn fuse_latest(self) -> FuseLatest<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        FuseLatest::new(self)
+    }
+
+    /// Groups consecutive items of `self` that have the same key
+    ///
+    /// Each group is yielded with its key once an item with a different key is received, or when
+    /// `self` ends.
+    #[cfg(feature = "alloc")]
+    fn group_by_key<K, F>(self, f: F) -> GroupByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        GroupByKey::new(self, f)
+    }
+
+    /// Yields the latest item of `self`, or `initial` if `self` has not yielded yet, whenever
+    /// polled
+    ///
+    /// Each poll drains the items `self` has ready to refresh the held value. The returned stream
+    /// is always ready, so it should be sampled by another stream (e.g. with
+    /// [`pace_by`](StreamTools::pace_by)) rather than polled in a loop. It never returns
+    /// `Pending` and ends when `self` ends, after yielding the last item of `self` if needed.
+    fn hold(self, initial: Self::Item) -> Hold<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Hold::new(self, initial)
+    }
+
+    /// Pairs each item of `self` with the constant index `i`
+    ///
+    /// Unlike [`StreamExt::enumerate`](futures::StreamExt::enumerate), the index does not change,
+    /// which is convenient to identify the source of items when merging several streams.
+    fn index_with(self, i: usize) -> IndexWith<Self>
+    where
+        Self: Sized,
+    {
+        IndexWith::new(self, i)
+    }
+
+    /// Calls `g` with the previous item, if any, and the current item for each item of `self`
+    ///
+    /// Items are yielded unchanged. A clone of the last item is kept to be passed to `g` along
+    /// with the next item, e.g. to log changes.
+    fn inspect_latest<G>(self, g: G) -> InspectLatest<Self, G>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        G: FnMut(Option<&Self::Item>, &Self::Item),
+    {
+        InspectLatest::new(self, g)
+    }
+
+    /// Yields items from `self` and `other` alternately
+    ///
+    /// The returned stream waits for the stream whose turn it is, even if the other stream has an
+    /// item ready, so that items strictly alternate. Once one stream ends, the remaining items of
+    /// the other stream are yielded.
+    fn interleave<S>(self, other: S) -> Interleave<Self, S>
+    where
+        Self: Sized,
+        S: Stream<Item = Self::Item>,
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Returns a future that drives `self` to completion and resolves to its last item
+    ///
+    /// The future resolves to `None` if `self` is empty. This is the async counterpart of
+    /// [`Iterator::last`].
+    fn last(self) -> Last<Self>
+    where
+        Self: Sized,
+    {
+        Last::new(self)
+    }
+
+    /// Yields `default` if `self` is pending before its first item, then the items of `self`
+    ///
+    /// `default` is yielded at most once and never after `self` yields an item, which lets
+    /// combinators like [`zip_latest`](StreamTools::zip_latest) start without waiting for a slow
+    /// stream. The returned stream ends when `self` ends.
+    fn latest_or(self, default: Self::Item) -> LatestOr<Self>
+    where
+        Self: Sized,
+    {
+        LatestOr::new(self, default)
+    }
+
+    /// Yields the values returned by `f` for the items of `self` until it returns `None`
+    ///
+    /// The returned stream ends on the first item for which `f` returns `None`. This is the stream
+    /// counterpart of [`Iterator::map_while`].
+    fn map_while_latest<F, T>(self, f: F) -> MapWhileLatest<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<T>,
+    {
+        MapWhileLatest::new(self, f)
+    }
+
+    /// Yields the items of `self` and `other` as they arrive, tagged with their source
+    ///
+    /// Items of `self` are wrapped in `Left` and items of `other` in `Right`. Unlike
+    /// [`zip_latest`](StreamTools::zip_latest), items are not paired. Both streams are polled in
+    /// turn for fairness. The returned stream ends when both streams end.
+    fn merge_latest<S>(self, other: S) -> MergeLatest<Self, S>
+    where
+        Self: Sized,
+        S: Stream,
+    {
+        MergeLatest::new(self, other)
+    }
+
+    /// Yields the items of `self`, waking the current task once when `self` is not ready
+    ///
+    /// When `self` returns `Pending`, the current task is woken like with
+    /// [`yield_now`](crate::future::yield_now), so that `self` is polled again after other tasks
+    /// had a chance to run. The task is woken only once until `self` yields again, so a stream
+    /// that stays pending does not cause a busy loop.
+    fn next_or_yield(self) -> NextOrYield<Self>
+    where
+        Self: Sized,
+    {
+        NextOrYield::new(self)
+    }
+
+    /// Yields the items of `self` and returns a flag telling whether the returned stream ended
+    ///
+    /// The flag is set to `true` once `self` ends, so it can be checked without access to the
+    /// returned stream, e.g. from another task. Items are yielded unchanged.
+    #[cfg(feature = "alloc")]
+    fn observe_terminated(self) -> (ObserveTerminated<Self>, Arc<AtomicBool>)
+    where
+        Self: Sized,
+    {
+        ObserveTerminated::new(self)
+    }
+
+    /// Yields the items of `self`, or `default` if `self` ends without yielding any item
+    fn on_empty(self, default: Self::Item) -> OnEmpty<Self>
+    where
+        Self: Sized,
+    {
+        OnEmpty::new(self, default)
+    }
+
+    /// Combines the latest item of `self` with each item of `pacer`
+    ///
+    /// Items are only emitted when `pacer` yields, so that the returned stream follows the cadence
+    /// of `pacer` regardless of how often `self` yields. Items yielded by `pacer` before `self`
+    /// produced an item are skipped.
+    ///
+    /// The returned stream ends when `pacer` ends, or if `self` ends without ever producing an
+    /// item.
+    ///
+    /// Visually, this gives:
+    /// ```text
+    /// ---0-1-2-3-4-5-6-7-8-9-------------> self
+    /// ---------a---------b-----c---------> pacer
+    /// ---------(3,a)-----(8,b)-(9,c)-----> self.pace_by(pacer, |x, t| (*x, *t))
+    /// ```
+    fn pace_by<S, F, T>(self, pacer: S, combine: F) -> PaceBy<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> T,
+    {
+        PaceBy::new(self, pacer, combine)
+    }
+
+    /// Returns a stream that can peek at the next item of `self` without consuming it
+    ///
+    /// See [`PeekableLatest::peek`] and [`PeekableLatest::poll_peek`].
+    fn peekable_latest(self) -> PeekableLatest<Self>
+    where
+        Self: Sized,
+    {
+        PeekableLatest::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    /// Splits a stream in two according to `f`
+    ///
+    /// Each item is passed to `f`. `Left` values are yielded by the first returned stream while
+    /// `Right` values are yielded by the second one. Polling either stream polls `self` and queues
+    /// the values destined to the other stream until it is polled. Values destined to a stream that
+    /// was dropped are discarded.
+    fn partition<F, L, R>(self, f: F) -> Partition<Self, F, L, R>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Either<L, R>,
+    {
+        partition::partition(self, f)
+    }
+
+    /// Yields the items of `self` and repeats the latest one each time `trigger` yields
+    ///
+    /// Items of `trigger` received before `self` yielded an item are ignored. The returned stream
+    /// ends when both `self` and `trigger` end.
+    fn repeat_latest<T>(self, trigger: T) -> RepeatLatest<Self, T>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        T: Stream,
+    {
+        RepeatLatest::new(self, trigger)
+    }
+
+    /// Yields the latest items received from `self` and `other` each time `trigger` yields
+    ///
+    /// Each item pairs the latest item of each stream received since the previous trigger, or
+    /// `None` for a stream that did not yield anything new in the meantime. The returned stream
+    /// ends when `trigger` ends.
+    fn sample_both<S, T>(self, other: S, trigger: T) -> SampleBoth<Self, S, T>
+    where
+        Self: Sized,
+        S: Stream,
+        T: Stream,
+    {
+        SampleBoth::new(self, other, trigger)
+    }
+
+    /// Yields the values returned by `f` for each item of `self`, skipping `None` values
+    ///
+    /// `f` receives a mutable reference to an accumulator initialized with `init` along with each
+    /// item. Unlike [`StreamExt::scan`](futures::StreamExt::scan), `f` returning `None` does not
+    /// end the returned stream; the item is skipped and the next items are still processed.
+    fn scan_latest<St, F, T>(self, init: St, f: F) -> ScanLatest<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item) -> Option<T>,
+    {
+        ScanLatest::new(self, init, f)
+    }
+
+    /// Yields the values returned by `f` for each item of `self`, yielding to the executor every
+    /// `yield_every` items
+    ///
+    /// `f` receives a mutable reference to an accumulator initialized with `init` along with each
+    /// item. After `yield_every` items, the returned stream wakes the current task and returns
+    /// `Pending` once, giving other tasks a chance to run. A `yield_every` of 0 is treated as 1.
+    fn scan_with_yield<St, F, T>(
+        self,
+        init: St,
+        f: F,
+        yield_every: usize,
+    ) -> ScanWithYield<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item) -> T,
+    {
+        ScanWithYield::new(self, init, f, yield_every)
+    }
+
+    /// Returns a future resolving to the first item of `self`, or `None` if it is empty, along with
+    /// `self`
+    ///
+    /// Unlike [`StreamExt::into_future`](futures::StreamExt::into_future), the remaining stream is
+    /// returned as is.
+    fn split_first(self) -> SplitFirst<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        SplitFirst::new(self)
+    }
+
+    /// Yields `items` before the items of `self`
+    fn start_with<I>(self, items: I) -> StartWith<Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+    {
+        StartWith::new(self, items.into_iter())
+    }
+
+    /// Yields the first item of `self` and then every `step`-th item
+    ///
+    /// Items in between are polled and dropped. `step_by(1)` yields all items.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        StepBy::new(self, step)
+    }
+
+    /// Yields the items of `self` until `until` completes
+    ///
+    /// `until` is polled before `self` every time the returned stream is polled. The returned
+    /// stream ends as soon as `until` completes, even if `self` has more items.
+    fn take_until_done<Fut>(self, until: Fut) -> TakeUntilDone<Self, Fut>
+    where
+        Self: Sized,
+        Fut: Future,
+    {
+        TakeUntilDone::new(self, until)
+    }
+
+    /// Yields the items of `self` while `pred` returns `true`
+    ///
+    /// The returned stream ends on the first item for which `pred` returns `false`. Unlike
+    /// [`StreamExt::take_while`](futures::StreamExt::take_while), `pred` is synchronous and this
+    /// item is also yielded if `include_last` is `true`.
+    fn take_while_latest<P>(self, pred: P, include_last: bool) -> TakeWhileLatest<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileLatest::new(self, pred, include_last)
+    }
+
+    /// Yields the items of `self` at the pace of `permits`
+    ///
+    /// An item is released each time `permits` yields while items are waiting. Items of `self` are
+    /// buffered until a permit is available, up to `capacity` items; `overflow` selects which item
+    /// is dropped when an item is received while the buffer is full. Permits received while no
+    /// item is waiting are not saved for later. A `capacity` of 0 is treated as 1.
+    ///
+    /// The returned stream ends when `self` ends and all buffered items have been released, or
+    /// when `permits` ends.
+    #[cfg(feature = "alloc")]
+    fn rate_limit<P>(self, permits: P, capacity: usize, overflow: Overflow) -> RateLimit<Self, P>
+    where
+        Self: Sized,
+        P: Stream<Item = ()>,
+    {
+        RateLimit::new(self, permits, capacity, overflow)
+    }
+
+    /// Yields the items of `self` after sending a copy of each of them to `sink`
+    ///
+    /// Each item is sent to `sink` and flushed before being yielded, so the returned stream is
+    /// slowed down if `sink` is not ready. `sink` is closed when `self` ends. If `sink` fails,
+    /// items keep being yielded but are no longer sent to it.
+    fn tee<K>(self, sink: K) -> Tee<Self, K>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Sink<Self::Item>,
+    {
+        Tee::new(self, sink)
+    }
+
+    /// Yields the first item of `self` in each window delimited by `window`
+    ///
+    /// The first item of `self` is yielded immediately. Subsequent items are dropped until `window`
+    /// yields, which opens a new window in which the next item of `self` is yielded. If `window`
+    /// ends, no new window is opened. The returned stream ends when `self` ends.
+    fn throttle_first<W>(self, window: W) -> ThrottleFirst<Self, W>
+    where
+        Self: Sized,
+        W: Stream,
+    {
+        ThrottleFirst::new(self, window)
+    }
+
+    /// Yields the last item of `self` in each window delimited by `window`
+    ///
+    /// Items of `self` replace the pending item, which is yielded when `window` yields and then
+    /// cleared. Ticks of `window` while no item is pending are skipped. When `self` ends, the
+    /// pending item is yielded if there is one and the returned stream ends. Unlike
+    /// [`debounce`](StreamTools::debounce), the returned stream does not end when `window` ends.
+    fn throttle_latest<W>(self, window: W) -> ThrottleLatest<Self, W>
+    where
+        Self: Sized,
+        W: Stream,
+    {
+        ThrottleLatest::new(self, window)
+    }
+
+    /// Yields the items of `self`, or [`Elapsed`] if `ticks` yields twice without `self` yielding
+    ///
+    /// Each item of `self` resets the count of ticks. Once `ticks` yields more than once since the
+    /// last item, `Err(Elapsed)` is yielded, the count is reset, and the returned stream continues
+    /// or ends according to `on_elapsed`. This gives deterministic timeouts, e.g. in tests.
+    fn timeout_via<Tk>(self, ticks: Tk, on_elapsed: OnElapsed) -> TimeoutVia<Self, Tk>
+    where
+        Self: Sized,
+        Tk: Stream,
+    {
+        TimeoutVia::new(self, ticks, on_elapsed)
+    }
+
+    /// Yields overlapping windows of the last `n` items of `self`
+    ///
+    /// A window is yielded each time `self` yields an item, once `n` items have been received.
+    /// Consecutive windows overlap: each one drops the oldest item of the previous window and ends
+    /// with the new item.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    #[cfg(feature = "alloc")]
+    fn windows(self, n: usize) -> Windows<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Windows::new(self, n)
+    }
+
     /// Zips two streams using their latest values when one is not ready
     ///
     /// The zipped stream keeps the latest items produced by both streams. If one of the underlying
@@ -40,6 +868,54 @@ pub trait StreamTools: Stream {
         ZipLatestWith::new(self, other, combine)
     }
 
+    /// Zips two streams like [`zip_latest_with`](StreamTools::zip_latest_with), keeping only the
+    /// newest items available
+    ///
+    /// Each poll drains both streams of their ready items before combining, so intermediate items
+    /// of a stream producing bursts are discarded instead of being combined one poll at a time.
+    /// Draining stops after a bounded number of items per poll, so a stream that is always ready
+    /// does not starve the task.
+    fn zip_latest_with_drained<S, F, T>(
+        self,
+        other: S,
+        combine: F,
+    ) -> ZipLatestWithDrained<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> T,
+    {
+        ZipLatestWithDrained::new(self, other, combine)
+    }
+
+    /// Zips two streams like [`zip_latest_with`](StreamTools::zip_latest_with), starting from seed
+    /// values
+    ///
+    /// `seed` and `other_seed` are used as the latest items of `self` and `other` until they
+    /// produce items, so that the first item produced by either stream is immediately combined.
+    /// Seeds count as produced items: the zipped stream ends when both underlying streams end.
+    ///
+    /// Visually, with `add` summing its arguments, this gives:
+    /// ```text
+    /// ---0-----------1-----------------2-------> self
+    /// --------------------|--------------------> other
+    /// ---100---------101---------------102-----> self.zip_latest_with_seeded(other, -1, 100, add)
+    /// ```
+    fn zip_latest_with_seeded<S, F, T>(
+        self,
+        other: S,
+        seed: Self::Item,
+        other_seed: S::Item,
+        combine: F,
+    ) -> ZipLatestWith<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> T,
+    {
+        ZipLatestWith::with_seeds(self, other, seed, other_seed, combine)
+    }
+
     /// Zips two streams using their latest values when one is not ready
     ///
     /// The zipped stream keeps a copy of the latest items produced by both streams. If one of the
@@ -47,7 +923,9 @@ pub trait StreamTools: Stream {
     /// returned alongside the latest item from the stream that did not yield anything new.
     ///
     /// The zipped stream ends when both underlying streams end, or if one of the streams ends
-    /// without ever producing an item.
+    /// without ever producing an item. Once a stream ends after producing items, its last item
+    /// keeps being paired with the new items of the other stream, so the zipped stream is infinite
+    /// if the other stream is.
     ///
     /// Visually, this gives:
     /// ```text
@@ -64,10 +942,225 @@ pub trait StreamTools: Stream {
     {
         ZipLatest::new(self, other)
     }
+
+    /// Zips two streams like [`zip_latest`](StreamTools::zip_latest) and shares the latest items
+    ///
+    /// Each item of `self` and `other` is moved into an [`Rc`](alloc::rc::Rc) when received, and
+    /// re-yielding it only clones the `Rc`. The items of `self` and `other` do not need to be
+    /// `Clone`.
+    #[cfg(feature = "alloc")]
+    fn zip_latest_shared<S>(self, other: S) -> ZipLatestShared<Self, S>
+    where
+        Self: Sized,
+        S: Stream,
+    {
+        ZipLatestShared::new(self, other)
+    }
+
+    /// Zips two streams like [`zip_latest`](StreamTools::zip_latest) and reports which streams
+    /// produced a new item
+    ///
+    /// Each item is tagged with [`Changed`] to tell whether `self`, `other` or both streams
+    /// yielded since the previous item.
+    fn zip_latest_changes<S>(self, other: S) -> ZipLatestChanges<Self, S>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        S: Stream,
+        S::Item: Clone,
+    {
+        ZipLatestChanges::new(self, other)
+    }
+
+    /// Zips two streams like [`zip_latest_with`](StreamTools::zip_latest_with) but passes
+    /// clones of the latest items to `combine`
+    ///
+    /// This is convenient when `combine` needs to own the items, e.g. to store them.
+    fn zip_latest_with_cloned<S, F, T>(
+        self,
+        other: S,
+        combine: F,
+    ) -> ZipLatestWithCloned<Self, S, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        S: Stream,
+        S::Item: Clone,
+        F: FnMut(Self::Item, S::Item) -> T,
+    {
+        ZipLatestWithCloned::new(self, other, combine)
+    }
+
+    /// Zips two streams like [`zip_latest_with`](StreamTools::zip_latest_with) with a fallible
+    /// `combine`
+    ///
+    /// The returned stream yields the results of `combine` and ends after the first error.
+    fn try_zip_latest_with<S, F, T, E>(self, other: S, combine: F) -> TryZipLatestWith<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> Result<T, E>,
+    {
+        TryZipLatestWith::new(self, other, combine)
+    }
+
+    /// Zips two streams like [`zip_latest_with`](StreamTools::zip_latest_with) but only yields
+    /// the values `combine` returns `Some` for
+    ///
+    /// When `combine` returns `None`, nothing is yielded for the current items and the underlying
+    /// streams keep being polled.
+    fn zip_latest_filter_map<S, F, T>(self, other: S, combine: F) -> ZipLatestFilterMap<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> Option<T>,
+    {
+        ZipLatestFilterMap::new(self, other, combine)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Zips two streams, keeping up to `backlog` unconsumed items from each stream
+    ///
+    /// Each emitted value combines the oldest unconsumed item of each stream, or the latest
+    /// consumed item of a stream that has no unconsumed item. Short bursts thus produce distinct
+    /// combinations instead of being collapsed to their latest item. A stream is not polled while
+    /// `backlog` of its items are waiting to be consumed. A `backlog` of 0 is treated as 1.
+    ///
+    /// The zipped stream ends when both underlying streams end and all their items have been
+    /// consumed, or if one of the streams ends without ever producing an item.
+    ///
+    /// Visually, with `add` summing its arguments, this gives:
+    /// ```text
+    /// ---1-2-3----------------------> self
+    /// ----------10------------------> other
+    /// ----------11-12-13------------> self.zip_latest_backlog(other, 4, add)
+    /// ```
+    fn zip_latest_backlog<S, F, T>(
+        self,
+        other: S,
+        backlog: usize,
+        combine: F,
+    ) -> ZipLatestBacklog<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> T,
+    {
+        ZipLatestBacklog::new(self, other, backlog, combine)
+    }
+
+    /// Zips two streams using their latest values and flags when each stream ends
+    ///
+    /// This behaves like [`zip_latest_with`](StreamTools::zip_latest_with) and yields the combined
+    /// values alongside `None`. When one of the streams ends after producing an item, one more
+    /// combined value is emitted alongside the [`Side`] of the stream that ended. Subsequent items
+    /// use the last value of that stream.
+    ///
+    /// Visually, with `add` summing its arguments, this gives:
+    /// ```text
+    /// ---0-----------1----|---------------------------> self
+    /// ------10-------11---------------12---|----------> other
+    /// ------10-------12---12,Left-----13---13,Right---> self.zip_latest_with_finalize(other, add)
+    /// ```
+    fn zip_latest_with_finalize<S, F, T>(
+        self,
+        other: S,
+        combine: F,
+    ) -> ZipLatestFinalize<Self, S, F>
+    where
+        Self: Sized,
+        S: Stream,
+        F: FnMut(&Self::Item, &S::Item) -> T,
+    {
+        ZipLatestFinalize::new(self, other, combine)
+    }
+
+    /// Zips three streams using their latest values when some are not ready
+    ///
+    /// This behaves like [`zip_latest_with`](StreamTools::zip_latest_with) with three streams. The
+    /// zipped stream ends when all underlying streams end, or if one of the streams ends without
+    /// ever producing an item.
+    fn zip_latest_with3<B, C, F, T>(self, b: B, c: C, combine: F) -> ZipLatestWith3<Self, B, C, F>
+    where
+        Self: Sized,
+        B: Stream,
+        C: Stream,
+        F: FnMut(&Self::Item, &B::Item, &C::Item) -> T,
+    {
+        ZipLatestWith3::new(self, b, c, combine)
+    }
+
+    /// Zips three streams using their latest values when some are not ready
+    ///
+    /// This behaves like [`zip_latest`](StreamTools::zip_latest) with three streams and yields flat
+    /// tuples.
+    ///
+    /// Visually, this gives:
+    /// ```text
+    /// ------a--------b-----------------------------c-------> self
+    /// ------0--------1--------2----------------------------> b
+    /// ------x-----------------------------y----------------> c
+    /// ------(a,0,x)--(b,1,x)--(b,2,x)-----(b,2,y)--(c,2,y)-> self.zip_latest3(b, c)
+    /// ```
+    fn zip_latest3<B, C>(self, b: B, c: C) -> ZipLatest3<Self, B, C>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Stream,
+        B::Item: Clone,
+        C: Stream,
+        C::Item: Clone,
+    {
+        ZipLatest3::new(self, b, c)
+    }
 }
 
 impl<S: Stream> StreamTools for S {}
 
+#[cfg(feature = "alloc")]
+/// Returns a `Future` that collects the items `stream` can produce without waiting
+///
+/// The returned future is always ready. It polls `stream` until it is not ready or ends, and
+/// resolves to the items produced in the meantime, which may be none.
+///
+/// Polling the returned future never returns if `stream` is always ready and never ends (e.g.
+/// [`repeat`](futures::stream::repeat)), as it keeps collecting items until memory runs out. Use
+/// [`StreamExt::take`](futures::StreamExt::take) to bound the number of items collected from such
+/// streams.
+pub fn drain_ready<S>(stream: &mut S) -> DrainReady<'_, S>
+where
+    S: Stream + Unpin,
+{
+    DrainReady::new(stream)
+}
+
+/// Returns an infinite stream yielding the values returned by `f`, yielding to the executor
+/// between items
+///
+/// Each item after the first one is preceded by a [`yield_now`](crate::future::yield_now), so a
+/// consumer processing items in a loop does not starve other tasks.
+pub fn repeat_with_yield<F, T>(f: F) -> RepeatWithYield<F>
+where
+    F: FnMut() -> T,
+{
+    RepeatWithYield::new(f)
+}
+
+/// Returns a stream yielding the items of the stream returned by `factory`, recreating it after
+/// an error
+///
+/// Items preceding an error are yielded. When the stream fails, it is dropped and replaced by a new
+/// one returned by `factory`. Once this happened `max` times, the next error is yielded and the
+/// returned stream ends.
+pub fn retry_stream<F, S>(factory: F, max: usize) -> RetryStream<F, S>
+where
+    F: FnMut() -> S,
+    S: TryStream,
+{
+    RetryStream::new(factory, max)
+}
+
+#[cfg(feature = "alloc")]
 /// Zips multiple streams using their latest values for the ones that are not ready
 ///
 /// The zipped stream keeps the latest items produced by all streams. If one of the underlying
@@ -77,6 +1170,14 @@ impl<S: Stream> StreamTools for S {}
 /// The zipped stream ends when all underlying streams end, or if one of the streams ends
 /// without ever producing an item.
 ///
+/// Each stream contributes at most one new item per poll and `combine` is called once all new
+/// items have been stored, so the slice it receives does not depend on the order in which the
+/// streams became ready.
+///
+/// `combine` borrows the latest items, which are neither cloned nor collected into a new `Vec` for
+/// each emission. The slice is only valid for the duration of the call, so `combine` must extract
+/// whatever it needs from it. This is the allocation-free form of [`zip_latest_all`].
+///
 /// Visually, this gives:
 /// ```text
 /// ---0-----------1-----------------2-------> a
@@ -92,6 +1193,184 @@ where
     ZipLatestWithAll::new(streams, combine)
 }
 
+#[cfg(feature = "std")]
+/// Zips multiple streams and combines the latest items of the streams that produced any so far
+///
+/// Unlike [`zip_latest_with_all`], the zipped stream does not wait for every stream to produce an
+/// item. `combine` receives the latest items keyed by the index of their stream in `streams`;
+/// streams that have not produced anything yet have no entry. An item is emitted whenever any
+/// stream produces a new item.
+///
+/// The zipped stream ends when all underlying streams end.
+pub fn zip_latest_with_all_sparse_map<I, F, T>(
+    streams: I,
+    combine: F,
+) -> ZipLatestWithAllSparseMap<I::Item, F>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    F: FnMut(&HashMap<usize, <I::Item as Stream>::Item>) -> T,
+{
+    ZipLatestWithAllSparseMap::new(streams, combine)
+}
+
+/// Zips multiple streams like [`zip_latest_with_all`] but ignores empty streams
+///
+/// Streams that end without producing any item are left out, so `combine` only receives the
+/// latest items of the streams that produced at least one item, in their original order. The
+/// positions of the items in the slice passed to `combine` are therefore compacted and may differ
+/// from the indices of the streams in `streams`. The zipped stream ends when all these streams
+/// end. It is empty if all streams are empty.
+#[cfg(feature = "alloc")]
+pub fn zip_latest_with_all_lossy<I, F, T>(streams: I, combine: F) -> ZipLatestWithAll<I::Item, F>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+{
+    ZipLatestWithAll::lossy(streams, combine)
+}
+
+#[cfg(feature = "alloc")]
+/// Zips multiple streams like [`zip_latest_with_all`] without requiring them to be [`Unpin`]
+///
+/// Each stream is pinned on the heap, which allows using streams built from async blocks without
+/// boxing them at the call site.
+pub fn zip_latest_with_all_pinned<I, F, T>(
+    streams: I,
+    combine: F,
+) -> ZipLatestWithAll<Pin<Box<I::Item>>, F>
+where
+    I: IntoIterator,
+    I::Item: Stream,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+{
+    ZipLatestWithAll::new(streams.into_iter().map(Box::pin), combine)
+}
+
+#[cfg(feature = "std")]
+/// Zips multiple streams like [`zip_latest_with_all`] and allows adding and removing streams
+///
+/// The returned [`ZipLatestWithAllHandle`] adds streams to or removes streams from the zipped
+/// stream while it runs. An added stream participates in the combined items once it has produced
+/// its first item, and a removed stream is dropped without ending the zipped stream.
+///
+/// Unlike [`zip_latest_with_all`], a stream that ends without ever producing an item is dropped
+/// without ending the zipped stream, whether it was passed initially or added later. The zipped
+/// stream ends when all handles are dropped and all streams end.
+///
+/// The slice passed to `combine` holds the latest item of each participating stream, in the order
+/// in which the streams produced their first item, the initial streams coming first in their
+/// original order. Removing a stream removes its item from the slice and shifts the following
+/// items down, so the remaining items keep their relative order.
+pub fn zip_latest_with_all_dynamic<I, F, T>(
+    streams: I,
+    combine: F,
+) -> (
+    ZipLatestWithAllDynamic<I::Item, F>,
+    ZipLatestWithAllHandle<I::Item>,
+)
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+{
+    ZipLatestWithAllDynamic::new(streams, combine)
+}
+
+#[cfg(feature = "alloc")]
+/// Zips multiple streams like [`zip_latest_with_all`] and combines the latest items again whenever
+/// `refresh` yields
+///
+/// Items yielded by `refresh` before all streams have produced an item are ignored.
+///
+/// Visually, with `add` summing its arguments, this gives:
+/// ```text
+/// ---0-----------1----------------------> a
+/// ------10----------------11------------> b
+/// ---------------------x-----------x----> refresh
+/// ------10-------11----11-12-------12---> zip_latest_with_all_with_refresh([a, b], refresh, add)
+/// ```
+pub fn zip_latest_with_all_with_refresh<I, G, F, T>(
+    streams: I,
+    refresh: G,
+    combine: F,
+) -> ZipLatestWithAllRefresh<I::Item, G, F>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    G: Stream,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+{
+    ZipLatestWithAllRefresh::new(streams, refresh, combine)
+}
+
+#[cfg(feature = "alloc")]
+/// Zips multiple streams like [`zip_latest_with_all`] and replaces the streams that end
+///
+/// When the stream at index `i` ends, `factories[i]` is called to create a replacement stream. The
+/// latest item of the stream that ended is used until the replacement produces an item. Streams
+/// without a factory are not replaced and behave as in [`zip_latest_with_all`].
+///
+/// The zipped stream ends when all streams end without being replaced, or if a stream without a
+/// factory ends without ever producing an item. A factory returning streams that end immediately is
+/// called repeatedly.
+pub fn zip_latest_with_all_resilient<I, Fac, F, T>(
+    streams: I,
+    factories: Vec<Fac>,
+    combine: F,
+) -> ZipLatestWithAllResilient<I::Item, Fac, F>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    Fac: FnMut() -> I::Item,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+{
+    ZipLatestWithAllResilient::new(streams, factories, combine)
+}
+
+#[cfg(feature = "std")]
+/// Zips multiple streams like [`zip_latest_with_all`] with a function combining the latest items
+/// that can be replaced through the returned [`CombineHandle`]
+pub fn zip_latest_with_all_swappable<I, S, T>(
+    streams: I,
+    combine: BoxCombine<S::Item, T>,
+) -> (SwappableZipLatestWithAll<S, T>, CombineHandle<S::Item, T>)
+where
+    I: IntoIterator<Item = S>,
+    S: Stream + Unpin,
+    S::Item: 'static,
+    T: 'static,
+{
+    let handle = CombineHandle::new(combine);
+    let shared = handle.clone();
+    let zipped = ZipLatestWithAll::new(
+        streams,
+        Box::new(move |items: &[_]| shared.combine(items)) as BoxCombine<_, _>,
+    );
+    (zipped, handle)
+}
+
+#[cfg(feature = "alloc")]
+/// Zips multiple streams like [`zip_latest_with_all`] and reports each poll to `observer`
+///
+/// `observer` is called once per call to `poll_next` with a [`ZipEvent`] describing what happened
+/// during that poll, which helps trace when the underlying streams are polled and combined.
+pub fn zip_latest_with_all_observed<I, F, T, O>(
+    streams: I,
+    combine: F,
+    observer: O,
+) -> ZipLatestWithAll<I::Item, F, O>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    F: FnMut(&[<I::Item as Stream>::Item]) -> T,
+    O: FnMut(ZipEvent),
+{
+    ZipLatestWithAll::with_observer(streams, combine, observer)
+}
+
+#[cfg(feature = "alloc")]
 /// Zips multiple streams using their latest values for the ones that are not ready
 ///
 /// The zipped stream keeps a copy of the latest items produced by all streams. If one of the
@@ -102,6 +1381,10 @@ where
 /// The zipped stream ends when all underlying streams end, or if one of the streams ends
 /// without ever producing an item.
 ///
+/// Each emission clones the latest items into a new `Vec`. Use [`zip_latest_with_all`] to borrow
+/// them instead, without allocating; the borrowed slice is then only valid during the call to the
+/// combining function.
+///
 /// Visually, this gives:
 /// ```text
 /// ---0--------------------1--------------------------> a
@@ -118,6 +1401,87 @@ where
     ZipLatestAll::new(streams)
 }
 
+/// Zips a fixed number of streams using their latest values
+///
+/// This behaves like [`zip_latest_all`] but yields arrays, which avoids allocating for each item.
+#[cfg(feature = "alloc")]
+pub fn zip_latest_all_array<S, const N: usize>(streams: [S; N]) -> ZipLatestAllArray<S, N>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    ZipLatestAllArray::new(streams)
+}
+
+/// Zips multiple labeled streams using their latest values
+///
+/// This behaves like [`zip_latest_all`] but yields maps from the label of each stream to its
+/// latest item.
+#[cfg(feature = "std")]
+pub fn zip_latest_labeled<I, K, S>(streams: I) -> ZipLatestLabeled<K, S>
+where
+    I: IntoIterator<Item = (K, S)>,
+    K: Clone + Eq + Hash,
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    ZipLatestLabeled::new(streams)
+}
+
+/// Zips multiple streams using their latest values and yields the latest items once, then only the
+/// new items
+///
+/// The first item is `Left` with the latest items of all streams, as yielded by
+/// [`zip_latest_all`]. Each later item is `Right` with the index of a stream and the new item it
+/// produced. If several streams produce an item at the same time, one item is yielded per stream,
+/// in the order the values were received.
+#[cfg(feature = "alloc")]
+pub fn first_then_deltas<I>(streams: I) -> FirstThenDeltas<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    <I::Item as Stream>::Item: Clone,
+{
+    FirstThenDeltas::new(streams)
+}
+
+#[cfg(feature = "alloc")]
+/// Zips multiple streams using their latest values and reports which streams produced new items
+///
+/// This behaves like [`zip_latest_all`] but each item is paired with the indices of the streams
+/// that produced a new value since the previous item, in the order the values were received. The
+/// first item lists every stream.
+pub fn zip_latest_all_indexed<I>(streams: I) -> ZipLatestAllIndexed<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    <I::Item as Stream>::Item: Clone,
+{
+    ZipLatestAllIndexed::new(streams)
+}
+
+/// Maximum number of items drained from a stream in a single poll
+const DRAIN_BUDGET: usize = 128;
+
+/// Polls the fused `stream` until it is not ready or the drain budget is exhausted, passing each
+/// item to `f`
+///
+/// Returns `true` if the budget was exhausted, in which case `stream` may still have ready items
+/// and has not registered a wake-up.
+fn drain_latest<S, F>(mut stream: Pin<&mut S>, ctx: &mut Context<'_>, mut f: F) -> bool
+where
+    S: Stream,
+    F: FnMut(S::Item),
+{
+    for _ in 0..DRAIN_BUDGET {
+        match stream.as_mut().poll_next(ctx) {
+            Poll::Ready(Some(x)) => f(x),
+            Poll::Ready(None) | Poll::Pending => return false,
+        }
+    }
+    true
+}
+
 #[cfg(test)]
 mod test_util {
     use crate::future::yield_now;
@@ -0,0 +1,115 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::abort_on`](crate::StreamTools::abort_on).
+#[pin_project]
+#[derive(Debug)]
+pub struct AbortOn<S, Fut> {
+    #[pin]
+    stream: S,
+    #[pin]
+    signal: Fut,
+    done: bool,
+    aborted: bool,
+}
+
+impl<S, Fut> AbortOn<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    pub(crate) fn new(stream: S, signal: Fut) -> Self {
+        Self {
+            stream,
+            signal,
+            done: false,
+            aborted: false,
+        }
+    }
+}
+
+impl<S, Fut> AbortOn<S, Fut> {
+    /// Returns whether this stream ended because the signal completed
+    ///
+    /// Returns `false` while the stream has not ended or if it ended because the underlying stream
+    /// was exhausted.
+    pub fn was_aborted(&self) -> bool {
+        self.aborted
+    }
+}
+
+impl<S, Fut> Stream for AbortOn<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        if this.signal.poll(ctx).is_ready() {
+            *this.done = true;
+            *this.aborted = true;
+            return Poll::Ready(None);
+        }
+        let res = this.stream.poll_next(ctx);
+        *this.done = matches!(res, Poll::Ready(None));
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<S, Fut> FusedStream for AbortOn<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{future::yield_n, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{iter, repeat},
+        StreamExt,
+    };
+    use std::pin::pin;
+
+    #[test]
+    fn signal_aborts_infinite_stream() {
+        let mut s = pin!(repeat(1).abort_on(yield_n(3)));
+        let actual = block_on(s.as_mut().collect::<Vec<_>>());
+        assert_eq!(actual, [1, 1, 1]);
+        assert!(s.was_aborted());
+    }
+
+    #[test]
+    fn exhaustion_is_not_an_abort() {
+        let mut s = pin!(iter([1, 2]).abort_on(yield_n(3)));
+        assert!(!s.was_aborted());
+        let actual = block_on(s.as_mut().collect::<Vec<_>>());
+        assert_eq!(actual, [1, 2]);
+        assert!(!s.was_aborted());
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use alloc::vec::Vec;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::batch_ready`](crate::StreamTools::batch_ready).
+#[pin_project]
+#[derive(Debug)]
+pub struct BatchReady<S> {
+    #[pin]
+    stream: Fuse<S>,
+}
+
+/// Alias of [`BatchReady`] returned by
+/// [`StreamTools::chunks_until_idle`](crate::StreamTools::chunks_until_idle)
+pub type ChunksUntilIdle<S> = BatchReady<S>;
+
+impl<S> BatchReady<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+        }
+    }
+}
+
+impl<S> Stream for BatchReady<S>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let mut batch = Vec::new();
+        drain_latest(this.stream.as_mut(), ctx, |item| batch.push(item));
+        if !batch.is_empty() {
+            Poll::Ready(Some(batch))
+        } else if this.stream.is_terminated() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S> FusedStream for BatchReady<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream::repeat, StreamExt};
+
+    #[test]
+    fn ready_items_are_batched() {
+        let items = yield_on_none([
+            Some(0),
+            Some(1),
+            Some(2),
+            None,
+            Some(3),
+            None,
+            None,
+            Some(4),
+        ]);
+        let actual = block_on(items.batch_ready().collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0, 1, 2], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn always_ready_stream_is_batched() {
+        let actual = block_on(repeat(1).batch_ready().take(2).collect::<Vec<_>>());
+        assert_eq!(actual.len(), 2);
+        assert!(actual
+            .iter()
+            .all(|batch| !batch.is_empty() && batch.iter().all(|&x| x == 1)));
+    }
+
+    #[test]
+    fn bursts_are_chunked_until_idle() {
+        let items = yield_on_none([Some(0), Some(1), None, Some(2), Some(3), Some(4)]);
+        let actual = block_on(items.chunks_until_idle().collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0, 1], vec![2, 3, 4]]);
+    }
+}
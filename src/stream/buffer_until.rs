@@ -0,0 +1,163 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use alloc::vec::Vec;
+use core::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::buffer_until`](crate::StreamTools::buffer_until).
+#[pin_project]
+#[derive(Debug)]
+pub struct BufferUntil<S, G>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    trigger: Fuse<G>,
+    batch: Vec<S::Item>,
+    skip_empty: bool,
+    done: bool,
+}
+
+impl<S, G> BufferUntil<S, G>
+where
+    S: Stream,
+    G: Stream,
+{
+    pub(crate) fn new(stream: S, trigger: G, skip_empty: bool) -> Self {
+        Self {
+            stream: stream.fuse(),
+            trigger: trigger.fuse(),
+            batch: Vec::new(),
+            skip_empty,
+            done: false,
+        }
+    }
+}
+
+impl<S, G> Stream for BufferUntil<S, G>
+where
+    S: Stream,
+    G: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let batch = &mut *this.batch;
+        let mut exhausted = drain_latest(this.stream.as_mut(), ctx, |x| batch.push(x));
+        if this.stream.is_done() {
+            *this.done = true;
+            return Poll::Ready(Some(mem::take(this.batch)).filter(|batch| !batch.is_empty()));
+        }
+        if this.batch.is_empty() && *this.skip_empty {
+            exhausted |= drain_latest(this.trigger.as_mut(), ctx, |_| {});
+        } else if let Poll::Ready(Some(_)) = this.trigger.as_mut().poll_next(ctx) {
+            return Poll::Ready(Some(mem::take(this.batch)));
+        }
+        if exhausted {
+            ctx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+}
+
+impl<S, G> FusedStream for BufferUntil<S, G>
+where
+    S: Stream,
+    G: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{pending, repeat},
+        task::noop_waker_ref,
+        StreamExt,
+    };
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn batches_are_emitted_on_trigger() {
+        let a = yield_on_none([
+            Some(0),
+            Some(1),
+            None,
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+        ]);
+        let trigger = yield_on_none([None, Some(()), None, Some(()), None, Some(())]);
+        let actual = block_on(a.buffer_until(trigger, false).collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0, 1], vec![2], vec![]]);
+    }
+
+    #[test]
+    fn empty_batches_can_be_skipped() {
+        let a = yield_on_none([
+            Some(0),
+            Some(1),
+            None,
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+        ]);
+        let trigger = yield_on_none([None, Some(()), None, Some(()), None, Some(())]);
+        let actual = block_on(a.buffer_until(trigger, true).collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn partial_batch_is_emitted_at_end() {
+        let a = yield_on_none([Some(0), None, None, Some(1), Some(2)]);
+        let trigger = yield_on_none([None, Some(())]);
+        let actual = block_on(a.buffer_until(trigger, true).collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0], vec![1, 2]]);
+    }
+
+    #[test]
+    fn always_ready_stream_does_not_prevent_triggering() {
+        let trigger = yield_on_none([None, Some(())]);
+        let actual = block_on(
+            repeat(1)
+                .buffer_until(trigger, false)
+                .take(1)
+                .collect::<Vec<_>>(),
+        );
+        let actual = actual.concat();
+        assert!(!actual.is_empty());
+        assert!(actual.iter().all(|&x| x == 1));
+    }
+
+    #[test]
+    fn always_ready_trigger_does_not_block_while_skipping_empty_batches() {
+        let mut buffered = pending::<i32>().buffer_until(repeat(()), true);
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(buffered.poll_next_unpin(&mut ctx), Poll::Pending);
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::buffered_latest`](crate::StreamTools::buffered_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct BufferedLatest<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    queue: VecDeque<S::Item>,
+    capacity: usize,
+}
+
+impl<S> BufferedLatest<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            stream: stream.fuse(),
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+impl<S> Stream for BufferedLatest<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while this.queue.len() < *this.capacity {
+            match this.stream.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(x)) => this.queue.push_back(x),
+                _ => break,
+            }
+        }
+        match this.queue.pop_front() {
+            Some(x) => Poll::Ready(Some(x)),
+            None if this.stream.is_terminated() => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let n = self.queue.len();
+        (
+            lower.saturating_add(n),
+            upper.and_then(|upper| upper.checked_add(n)),
+        )
+    }
+}
+
+impl<S> FusedStream for BufferedLatest<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+    use std::cell::Cell;
+
+    #[test]
+    fn items_are_prefetched_up_to_capacity() {
+        let pulled = Cell::new(0);
+        let mut buffered = stream::iter(0..10)
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .buffered_latest(3);
+        let mut actual = Vec::new();
+        while let Some(x) = block_on(buffered.next()) {
+            actual.push(x);
+            assert!(pulled.get() - actual.len() < 3);
+        }
+        assert_eq!(actual, (0..10).collect::<Vec<_>>());
+        assert_eq!(pulled.get(), 10);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::vec::Vec;
+use core::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::chunks_of`](crate::StreamTools::chunks_of).
+#[pin_project]
+#[derive(Debug)]
+pub struct ChunksOf<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    chunk: Vec<S::Item>,
+    size: usize,
+}
+
+impl<S> ChunksOf<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, size: usize) -> Self {
+        assert!(size > 0, "chunk size must be positive");
+        Self {
+            stream: stream.fuse(),
+            chunk: Vec::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<S> Stream for ChunksOf<S>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while let Poll::Ready(x) = this.stream.as_mut().poll_next(ctx) {
+            match x {
+                Some(x) => {
+                    this.chunk.push(x);
+                    if this.chunk.len() == *this.size {
+                        let next = Vec::with_capacity(*this.size);
+                        return Poll::Ready(Some(mem::replace(this.chunk, next)));
+                    }
+                }
+                None => {
+                    return Poll::Ready(Some(mem::take(this.chunk)).filter(|c| !c.is_empty()));
+                }
+            }
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.chunk.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(buffered) / self.size;
+        let upper = upper
+            .and_then(|upper| upper.checked_add(buffered))
+            .map(|upper| upper.div_ceil(self.size));
+        (lower, upper)
+    }
+}
+
+impl<S> FusedStream for ChunksOf<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.chunk.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, Stream, StreamExt};
+
+    #[test]
+    fn chunks_are_full_when_length_is_a_multiple_of_size() {
+        let actual = block_on(stream::iter(0..6).chunks_of(3).collect::<Vec<_>>());
+        assert_eq!(actual, [[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn last_chunk_can_be_partial() {
+        let actual = block_on(stream::iter(0..5).chunks_of(2).collect::<Vec<_>>());
+        assert_eq!(actual, [vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn chunks_wait_for_enough_items() {
+        let items = yield_on_none([Some(0), None, Some(1), Some(2), None, Some(3)]);
+        let actual = block_on(items.chunks_of(2).collect::<Vec<_>>());
+        assert_eq!(actual, [[0, 1], [2, 3]]);
+    }
+
+    #[test]
+    fn size_hint_rounds_up() {
+        let chunks = stream::iter(0..5).chunks_of(2);
+        assert_eq!(chunks.size_hint(), (2, Some(3)));
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{future::FusedFuture, stream::Fuse, Stream, StreamExt};
+use pin_project::pin_project;
+
+/// Future returned by
+/// [`StreamTools::collect_latest_into`](crate::StreamTools::collect_latest_into).
+#[pin_project]
+#[derive(Debug)]
+pub struct CollectLatestInto<S, St, F> {
+    #[pin]
+    stream: Fuse<S>,
+    acc: Option<St>,
+    f: F,
+}
+
+impl<S, St, F> CollectLatestInto<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item),
+{
+    pub(crate) fn new(stream: S, init: St, f: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            acc: Some(init),
+            f,
+        }
+    }
+}
+
+impl<S, St, F> Future for CollectLatestInto<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item),
+{
+    type Output = St;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<St> {
+        let mut this = self.project();
+        let acc = this
+            .acc
+            .as_mut()
+            .expect("CollectLatestInto polled after completion");
+        while let Poll::Ready(x) = this.stream.as_mut().poll_next(ctx) {
+            match x {
+                Some(x) => (this.f)(acc, x),
+                None => return Poll::Ready(this.acc.take().unwrap()),
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<S, St, F> FusedFuture for CollectLatestInto<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item),
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::executor::block_on;
+
+    #[test]
+    fn emissions_are_folded_into_accumulator() {
+        let a = yield_on_none([Some(0), None, Some(1)]);
+        let b = yield_on_none([Some(10), None, None, Some(11)]);
+        let zipped = a.zip_latest(b);
+        let sum = block_on(zipped.collect_latest_into(0, |sum, (i, j)| *sum += i + j));
+        assert_eq!(sum, 10 + 11 + 12);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::debounce`](crate::StreamTools::debounce).
+#[pin_project]
+#[derive(Debug)]
+pub struct Debounce<S, Q>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    quiet: Fuse<Q>,
+    pending: Option<S::Item>,
+}
+
+impl<S, Q> Debounce<S, Q>
+where
+    S: Stream,
+    Q: Stream,
+{
+    pub(crate) fn new(stream: S, quiet: Q) -> Self {
+        Self {
+            stream: stream.fuse(),
+            quiet: quiet.fuse(),
+            pending: None,
+        }
+    }
+}
+
+impl<S, Q> Stream for Debounce<S, Q>
+where
+    S: Stream,
+    Q: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let pending = &mut *this.pending;
+        let mut exhausted = drain_latest(this.stream.as_mut(), ctx, |x| *pending = Some(x));
+        if this.stream.is_done() {
+            return Poll::Ready(this.pending.take());
+        }
+        let res = if this.pending.is_none() {
+            exhausted |= drain_latest(this.quiet.as_mut(), ctx, |_| {});
+            if this.quiet.is_done() {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            }
+        } else {
+            match this.quiet.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(_)) => Poll::Ready(this.pending.take()),
+                Poll::Ready(None) => {
+                    *this.pending = None;
+                    Poll::Ready(None)
+                }
+                Poll::Pending => Poll::Pending,
+            }
+        };
+        if exhausted && res.is_pending() {
+            ctx.waker().wake_by_ref();
+        }
+        res
+    }
+}
+
+impl<S, Q> FusedStream for Debounce<S, Q>
+where
+    S: Stream,
+    Q: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending.is_none() && (self.stream.is_done() || self.quiet.is_done())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{channel::mpsc, executor::block_on, poll, stream::repeat, StreamExt};
+
+    #[test]
+    fn rapid_updates_collapse_to_latest_at_quiet_tick() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (quiet_sender, quiet) = mpsc::unbounded();
+        let mut debounced = receiver.debounce(quiet);
+        block_on(async {
+            sender.unbounded_send(0).unwrap();
+            sender.unbounded_send(1).unwrap();
+            sender.unbounded_send(2).unwrap();
+            assert!(poll!(debounced.next()).is_pending());
+            quiet_sender.unbounded_send(()).unwrap();
+            assert_eq!(debounced.next().await, Some(2));
+            quiet_sender.unbounded_send(()).unwrap();
+            assert!(poll!(debounced.next()).is_pending());
+            sender.unbounded_send(3).unwrap();
+            sender.unbounded_send(4).unwrap();
+            quiet_sender.unbounded_send(()).unwrap();
+            assert_eq!(debounced.next().await, Some(4));
+            sender.unbounded_send(5).unwrap();
+            drop(sender);
+            assert_eq!(debounced.next().await, Some(5));
+            assert_eq!(debounced.next().await, None);
+        });
+    }
+
+    #[test]
+    fn always_ready_stream_is_debounced() {
+        let quiet = yield_on_none([None, Some(()), None, Some(())]);
+        let actual = block_on(repeat(1).debounce(quiet).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 1]);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::dedup_by_key`](crate::StreamTools::dedup_by_key).
+#[pin_project]
+#[derive(Debug)]
+pub struct DedupByKey<S, F, K> {
+    #[pin]
+    stream: Fuse<S>,
+    key: F,
+    last: Option<K>,
+}
+
+impl<S, F, K> DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    pub(crate) fn new(stream: S, key: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            key,
+            last: None,
+        }
+    }
+}
+
+impl<S, F, K> Stream for DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(x) = ready!(this.stream.as_mut().poll_next(ctx)) else {
+                return Poll::Ready(None);
+            };
+            let k = (this.key)(&x);
+            if this.last.as_ref() != Some(&k) {
+                *this.last = Some(k);
+                return Poll::Ready(Some(x));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S, F, K> FusedStream for DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[derive(Debug, PartialEq)]
+    struct Record {
+        id: u32,
+        payload: &'static str,
+    }
+
+    #[test]
+    fn consecutive_items_with_same_key_are_dropped() {
+        let records = [(1, "a"), (1, "b"), (2, "c"), (2, "d"), (1, "e")]
+            .map(|(id, payload)| Record { id, payload });
+        let actual = block_on(
+            stream::iter(records)
+                .dedup_by_key(|r| r.id)
+                .map(|r| r.payload)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, ["a", "c", "e"]);
+    }
+}
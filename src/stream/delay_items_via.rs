@@ -0,0 +1,126 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::delay_items_via`](crate::StreamTools::delay_items_via).
+#[pin_project]
+#[derive(Debug)]
+pub struct DelayItemsVia<S, Tk>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    ticks: Fuse<Tk>,
+    queue: VecDeque<S::Item>,
+    done: bool,
+}
+
+impl<S, Tk> DelayItemsVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    pub(crate) fn new(stream: S, ticks: Tk) -> Self {
+        Self {
+            stream: stream.fuse(),
+            ticks: ticks.fuse(),
+            queue: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+impl<S, Tk> Stream for DelayItemsVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let queue = &mut *this.queue;
+        let exhausted = drain_latest(this.stream.as_mut(), ctx, |x| queue.push_back(x));
+        if this.queue.is_empty() {
+            *this.done = this.stream.is_done();
+            return if *this.done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+        match this.ticks.poll_next(ctx) {
+            Poll::Ready(Some(_)) => Poll::Ready(this.queue.pop_front()),
+            Poll::Ready(None) => {
+                *this.done = true;
+                this.queue.clear();
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                if exhausted {
+                    ctx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<S, Tk> FusedStream for DelayItemsVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use core::task::Poll;
+    use futures::{channel::mpsc, executor::block_on, poll, stream, SinkExt, StreamExt};
+
+    #[test]
+    fn burst_is_paced_by_ticks() {
+        block_on(async {
+            let (mut tick_tx, tick_rx) = mpsc::unbounded::<()>();
+            let mut delayed = stream::iter(0..5).delay_items_via(tick_rx);
+            for i in 0..5 {
+                assert_eq!(poll!(delayed.next()), Poll::Pending);
+                tick_tx.send(()).await.unwrap();
+                assert_eq!(poll!(delayed.next()), Poll::Ready(Some(i)));
+            }
+            assert_eq!(poll!(delayed.next()), Poll::Ready(None));
+        });
+    }
+
+    #[test]
+    fn stream_ends_when_ticks_end() {
+        let delayed = stream::iter(0..5).delay_items_via(stream::iter([(), ()]));
+        assert_eq!(block_on(delayed.collect::<Vec<_>>()), [0, 1]);
+    }
+
+    #[test]
+    fn always_ready_stream_is_paced() {
+        let ticks = yield_on_none([None, Some(()), None, Some(())]);
+        let delayed = stream::repeat(1).delay_items_via(ticks);
+        assert_eq!(block_on(delayed.collect::<Vec<_>>()), [1, 1]);
+    }
+}
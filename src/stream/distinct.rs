@@ -0,0 +1,89 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    hash::Hash,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+use std::collections::HashSet;
+
+/// Stream returned by [`StreamTools::distinct`](crate::StreamTools::distinct).
+#[pin_project]
+#[derive(Debug)]
+pub struct Distinct<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    seen: HashSet<S::Item>,
+}
+
+impl<S> Distinct<S>
+where
+    S: Stream,
+    S::Item: Clone + Eq + Hash,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<S> Stream for Distinct<S>
+where
+    S: Stream,
+    S::Item: Clone + Eq + Hash,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(x) = ready!(this.stream.as_mut().poll_next(ctx)) else {
+                return Poll::Ready(None);
+            };
+            if !this.seen.contains(&x) {
+                this.seen.insert(x.clone());
+                return Poll::Ready(Some(x));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<S> FusedStream for Distinct<S>
+where
+    S: Stream,
+    S::Item: Clone + Eq + Hash,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn items_seen_before_are_dropped() {
+        let actual = block_on(
+            stream::iter([1, 2, 1, 3, 2, 4])
+                .distinct()
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [1, 2, 3, 4]);
+    }
+}
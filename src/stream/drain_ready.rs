@@ -0,0 +1,52 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::Stream;
+
+/// Future returned by [`drain_ready`](crate::stream::drain_ready).
+#[derive(Debug)]
+pub struct DrainReady<'a, S>(&'a mut S);
+
+impl<'a, S> DrainReady<'a, S>
+where
+    S: Stream + Unpin,
+{
+    pub(crate) fn new(stream: &'a mut S) -> Self {
+        Self(stream)
+    }
+}
+
+impl<S> Future for DrainReady<'_, S>
+where
+    S: Stream + Unpin,
+{
+    type Output = Vec<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = Pin::new(&mut *self.0).poll_next(ctx) {
+            items.push(item);
+        }
+        Poll::Ready(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::{drain_ready, test_util::yield_on_none};
+    use futures::{executor::block_on, pin_mut};
+
+    #[test]
+    fn ready_prefix_is_drained() {
+        let a = yield_on_none([Some(0), Some(1), None, Some(2)]);
+        pin_mut!(a);
+        assert_eq!(block_on(drain_ready(&mut a)), [0, 1]);
+        assert_eq!(block_on(drain_ready(&mut a)), [2]);
+        assert_eq!(block_on(drain_ready(&mut a)), []);
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::enumerate_from`](crate::StreamTools::enumerate_from).
+#[pin_project]
+#[derive(Debug)]
+pub struct EnumerateFrom<S> {
+    #[pin]
+    stream: Fuse<S>,
+    count: usize,
+}
+
+/// Alias of [`EnumerateFrom`] returned by
+/// [`StreamTools::count_latest`](crate::StreamTools::count_latest).
+pub type CountLatest<S> = EnumerateFrom<S>;
+
+impl<S> EnumerateFrom<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, start: usize) -> Self {
+        Self {
+            stream: stream.fuse(),
+            count: start,
+        }
+    }
+}
+
+impl<S> Stream for EnumerateFrom<S>
+where
+    S: Stream,
+{
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = ready!(this.stream.poll_next(ctx));
+        Poll::Ready(item.map(|item| {
+            let i = *this.count;
+            *this.count = i.saturating_add(1);
+            (i, item)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S> FusedStream for EnumerateFrom<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn counter_starts_at_given_index() {
+        let actual = block_on(
+            stream::iter("abc".chars())
+                .enumerate_from(100)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [(100, 'a'), (101, 'b'), (102, 'c')]);
+    }
+
+    #[test]
+    fn counter_saturates() {
+        let actual = block_on(
+            stream::iter(0..3)
+                .enumerate_from(usize::MAX - 1)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            actual,
+            [(usize::MAX - 1, 0), (usize::MAX, 1), (usize::MAX, 2)]
+        );
+    }
+
+    #[test]
+    fn count_includes_current_item() {
+        let actual = block_on(
+            stream::iter("abc".chars())
+                .count_latest()
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::flatten_latest`](crate::StreamTools::flatten_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct FlattenLatest<S>
+where
+    S: Stream,
+{
+    #[pin]
+    outer: Fuse<S>,
+    #[pin]
+    inner: Option<S::Item>,
+}
+
+impl<S> FlattenLatest<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    pub(crate) fn new(outer: S) -> Self {
+        Self {
+            outer: outer.fuse(),
+            inner: None,
+        }
+    }
+}
+
+impl<S> Stream for FlattenLatest<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    type Item = <S::Item as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let inner = &mut this.inner;
+        let exhausted = drain_latest(this.outer.as_mut(), ctx, |s| inner.set(Some(s)));
+        let res = match this.inner.as_mut().as_pin_mut().map(|s| s.poll_next(ctx)) {
+            Some(Poll::Ready(Some(x))) => Poll::Ready(Some(x)),
+            Some(Poll::Pending) => Poll::Pending,
+            Some(Poll::Ready(None)) | None => {
+                this.inner.set(None);
+                if this.outer.is_done() {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+        };
+        if exhausted && res.is_pending() {
+            ctx.waker().wake_by_ref();
+        }
+        res
+    }
+}
+
+impl<S> FusedStream for FlattenLatest<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.outer.is_terminated() && self.inner.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        channel::mpsc,
+        executor::block_on,
+        stream::{iter, repeat},
+        StreamExt,
+    };
+
+    #[test]
+    fn newer_inner_stream_preempts_previous_one() {
+        let (outer_sender, outer) = mpsc::unbounded();
+        let (first_sender, first) = mpsc::unbounded();
+        let (second_sender, second) = mpsc::unbounded();
+        let mut flattened = outer.flatten_latest();
+        block_on(async {
+            first_sender.unbounded_send(1).unwrap();
+            outer_sender.unbounded_send(first).unwrap();
+            assert_eq!(flattened.next().await, Some(1));
+            first_sender.unbounded_send(2).unwrap();
+            second_sender.unbounded_send(10).unwrap();
+            outer_sender.unbounded_send(second).unwrap();
+            assert_eq!(flattened.next().await, Some(10));
+            drop(outer_sender);
+            second_sender.unbounded_send(11).unwrap();
+            assert_eq!(flattened.next().await, Some(11));
+            drop(second_sender);
+            assert_eq!(flattened.next().await, None);
+        });
+        assert!(first_sender.is_closed());
+    }
+
+    #[test]
+    fn always_ready_outer_stream_does_not_prevent_yielding() {
+        let actual = block_on(
+            repeat(iter([1]))
+                .flatten_latest()
+                .take(3)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [1, 1, 1]);
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    future::FusedFuture,
+    stream::{Fuse, FusedStream},
+    Sink, Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Future returned by [`StreamTools::forward_all`](crate::StreamTools::forward_all).
+#[pin_project]
+#[derive(Debug)]
+pub struct ForwardAll<S, Sk>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    sinks: Vec<Target<Sk>>,
+    buffer: Option<S::Item>,
+    done: bool,
+}
+
+impl<S, Sk> ForwardAll<S, Sk>
+where
+    S: Stream,
+    S::Item: Clone,
+    Sk: Sink<S::Item> + Unpin,
+{
+    pub(crate) fn new<I>(stream: S, sinks: I) -> Self
+    where
+        I: IntoIterator<Item = Sk>,
+    {
+        Self {
+            stream: stream.fuse(),
+            sinks: sinks
+                .into_iter()
+                .map(|sink| Target {
+                    sink,
+                    pending: false,
+                    closed: false,
+                })
+                .collect(),
+            buffer: None,
+            done: false,
+        }
+    }
+}
+
+impl<S, Sk> Future for ForwardAll<S, Sk>
+where
+    S: Stream,
+    S::Item: Clone,
+    Sk: Sink<S::Item> + Unpin,
+{
+    type Output = Result<(), Sk::Error>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(Ok(()));
+        }
+        let res = poll_forward(this.stream, this.sinks, this.buffer, ctx);
+        *this.done = res.is_ready();
+        res
+    }
+}
+
+impl<S, Sk> FusedFuture for ForwardAll<S, Sk>
+where
+    S: Stream,
+    S::Item: Clone,
+    Sk: Sink<S::Item> + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+fn poll_forward<S, Sk>(
+    mut stream: Pin<&mut Fuse<S>>,
+    sinks: &mut [Target<Sk>],
+    buffer: &mut Option<S::Item>,
+    ctx: &mut Context<'_>,
+) -> Poll<Result<(), Sk::Error>>
+where
+    S: Stream,
+    S::Item: Clone,
+    Sk: Sink<S::Item> + Unpin,
+{
+    loop {
+        if let Some(item) = buffer {
+            let mut sent = true;
+            for target in sinks.iter_mut().filter(|t| t.pending) {
+                let mut sink = Pin::new(&mut target.sink);
+                if sink.as_mut().poll_ready(ctx)?.is_ready() {
+                    sink.start_send(item.clone())?;
+                    target.pending = false;
+                } else {
+                    sent = false;
+                }
+            }
+            if !sent {
+                return Poll::Pending;
+            }
+            *buffer = None;
+        }
+        if stream.is_terminated() {
+            break;
+        }
+        match stream.as_mut().poll_next(ctx) {
+            Poll::Ready(Some(item)) => {
+                *buffer = Some(item);
+                sinks.iter_mut().for_each(|t| t.pending = true);
+            }
+            Poll::Ready(None) => break,
+            Poll::Pending => {
+                for target in sinks.iter_mut() {
+                    let _ = Pin::new(&mut target.sink).poll_flush(ctx)?;
+                }
+                return Poll::Pending;
+            }
+        }
+    }
+    let mut closed = true;
+    for target in sinks.iter_mut().filter(|t| !t.closed) {
+        match Pin::new(&mut target.sink).poll_close(ctx)? {
+            Poll::Ready(()) => target.closed = true,
+            Poll::Pending => closed = false,
+        }
+    }
+    if closed {
+        Poll::Ready(Ok(()))
+    } else {
+        Poll::Pending
+    }
+}
+
+#[derive(Debug)]
+struct Target<Sk> {
+    sink: Sk,
+    pending: bool,
+    closed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{channel::mpsc, executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn all_sinks_receive_all_items() {
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..3).map(|_| mpsc::unbounded()).unzip();
+        block_on(stream::iter(0..5).forward_all(senders)).unwrap();
+        for receiver in receivers {
+            assert_eq!(block_on(receiver.collect::<Vec<_>>()), [0, 1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn sink_error_is_returned() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (closed_sender, _) = mpsc::unbounded();
+        closed_sender.close_channel();
+        let res = block_on(stream::iter(0..5).forward_all([sender, closed_sender]));
+        assert!(res.is_err());
+        drop(receiver);
+    }
+}
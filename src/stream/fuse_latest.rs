@@ -0,0 +1,93 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::fuse_latest`](crate::StreamTools::fuse_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct FuseLatest<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    last: Option<S::Item>,
+}
+
+impl<S> FuseLatest<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            last: None,
+        }
+    }
+
+    /// Returns the last item yielded, if any
+    ///
+    /// The last item remains available after the stream ends. As
+    /// [`StreamTools::last`](crate::StreamTools::last) takes precedence in method call syntax,
+    /// this must be called as `FuseLatest::last(&stream)`.
+    pub fn last(&self) -> Option<&S::Item> {
+        self.last.as_ref()
+    }
+}
+
+impl<S> Stream for FuseLatest<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let Some(x) = ready!(this.stream.poll_next(ctx)) else {
+            return Poll::Ready(None);
+        };
+        *this.last = Some(x.clone());
+        Poll::Ready(Some(x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S> FusedStream for FuseLatest<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::FuseLatest, StreamTools};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn last_item_is_kept_after_completion() {
+        block_on(async {
+            let mut fused = stream::iter(0..3).fuse_latest();
+            assert_eq!(FuseLatest::last(&fused), None);
+            while fused.next().await.is_some() {}
+            assert_eq!(fused.next().await, None);
+            assert_eq!(FuseLatest::last(&fused), Some(&2));
+        });
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::{vec, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::group_by_key`](crate::StreamTools::group_by_key).
+#[pin_project]
+#[derive(Debug)]
+pub struct GroupByKey<S, F, K>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    key: F,
+    group: Option<(K, Vec<S::Item>)>,
+}
+
+impl<S, F, K> GroupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    pub(crate) fn new(stream: S, key: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            key,
+            group: None,
+        }
+    }
+}
+
+impl<S, F, K> Stream for GroupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<S::Item>);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(ctx)) {
+                Some(x) => {
+                    let k = (this.key)(&x);
+                    match this.group {
+                        Some((key, items)) if *key == k => items.push(x),
+                        _ => {
+                            let group = this.group.replace((k, vec![x]));
+                            if group.is_some() {
+                                return Poll::Ready(group);
+                            }
+                        }
+                    }
+                }
+                None => return Poll::Ready(this.group.take()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.group.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if lower > 0 { 1 } else { pending };
+        let upper = upper.and_then(|upper| upper.checked_add(pending));
+        (lower, upper)
+    }
+}
+
+impl<S, F, K> FusedStream for GroupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.group.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn consecutive_items_with_same_key_are_grouped() {
+        let items = stream::iter([1, 1, 2, 3, 3, 3, 1]);
+        let actual = block_on(items.group_by_key(|&x| x).collect::<Vec<_>>());
+        assert_eq!(
+            actual,
+            [
+                (1, vec![1, 1]),
+                (2, vec![2]),
+                (3, vec![3, 3, 3]),
+                (1, vec![1])
+            ],
+        );
+    }
+
+    #[test]
+    fn group_waits_for_key_change() {
+        let items = yield_on_none([Some(0), None, Some(2), None, Some(3)]);
+        let actual = block_on(items.group_by_key(|x| x % 2).collect::<Vec<_>>());
+        assert_eq!(actual, [(0, vec![0, 2]), (1, vec![3])]);
+    }
+}
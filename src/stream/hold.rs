@@ -0,0 +1,128 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::hold`](crate::StreamTools::hold).
+#[pin_project]
+#[derive(Debug)]
+pub struct Hold<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    latest: S::Item,
+    done: bool,
+}
+
+impl<S> Hold<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub(crate) fn new(stream: S, initial: S::Item) -> Self {
+        Self {
+            stream: stream.fuse(),
+            latest: initial,
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for Hold<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let latest = &mut *this.latest;
+        let mut updated = false;
+        drain_latest(this.stream.as_mut(), ctx, |x| {
+            *latest = x;
+            updated = true;
+        });
+        if this.stream.is_done() {
+            *this.done = true;
+            if !updated {
+                return Poll::Ready(None);
+            }
+        }
+        Poll::Ready(Some(this.latest.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
+    }
+}
+
+impl<S> FusedStream for Hold<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        channel::mpsc,
+        executor::{block_on, block_on_stream},
+        stream::{iter, repeat},
+        Stream, StreamExt,
+    };
+
+    #[test]
+    fn latest_item_is_held() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut held = receiver.hold(0);
+        block_on(async {
+            assert_eq!(held.next().await, Some(0));
+            sender.unbounded_send(1).unwrap();
+            assert_eq!(held.next().await, Some(1));
+            assert_eq!(held.next().await, Some(1));
+            sender.unbounded_send(2).unwrap();
+            sender.unbounded_send(3).unwrap();
+            assert_eq!(held.next().await, Some(3));
+            sender.unbounded_send(4).unwrap();
+            drop(sender);
+            assert_eq!(held.next().await, Some(4));
+            assert_eq!(held.next().await, None);
+        });
+    }
+
+    #[test]
+    fn held_stream_can_be_collected_as_iterator() {
+        let held = iter([1, 2]).hold(0);
+        assert_eq!(held.size_hint(), (1, None));
+        assert_eq!(block_on_stream(held).collect::<Vec<_>>(), [2]);
+    }
+
+    #[test]
+    fn always_ready_stream_does_not_prevent_yielding() {
+        let actual = block_on(repeat(1).hold(0).take(2).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 1]);
+    }
+}
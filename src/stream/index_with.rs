@@ -0,0 +1,73 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::fuse::Fuse;
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::index_with`](crate::StreamTools::index_with).
+#[pin_project]
+#[derive(Clone, Debug)]
+pub struct IndexWith<S> {
+    #[pin]
+    stream: Fuse<S>,
+    index: usize,
+}
+
+impl<S> IndexWith<S> {
+    pub(crate) fn new(stream: S, index: usize) -> Self {
+        Self {
+            stream: Fuse::new(stream),
+            index,
+        }
+    }
+
+    /// Returns the index paired with the items of this stream
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<S> Stream for IndexWith<S>
+where
+    S: Stream,
+{
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let x = ready!(this.stream.poll_next(ctx));
+        Poll::Ready(x.map(|x| (*this.index, x)))
+    }
+}
+
+impl<S> FusedStream for IndexWith<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        executor::block_on,
+        stream::{self, FusedStream},
+        StreamExt,
+    };
+
+    #[test]
+    fn items_are_paired_with_constant_index() {
+        let mut s = stream::iter("abc".chars()).index_with(7);
+        assert_eq!(s.index(), 7);
+        let actual = block_on((&mut s).collect::<Vec<_>>());
+        assert_eq!(actual, [(7, 'a'), (7, 'b'), (7, 'c')]);
+        assert!(s.is_terminated());
+    }
+}
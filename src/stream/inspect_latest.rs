@@ -0,0 +1,91 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::inspect_latest`](crate::StreamTools::inspect_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct InspectLatest<S, G>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    g: G,
+    previous: Option<S::Item>,
+}
+
+impl<S, G> InspectLatest<S, G>
+where
+    S: Stream,
+    S::Item: Clone,
+    G: FnMut(Option<&S::Item>, &S::Item),
+{
+    pub(crate) fn new(stream: S, g: G) -> Self {
+        Self {
+            stream: stream.fuse(),
+            g,
+            previous: None,
+        }
+    }
+}
+
+impl<S, G> Stream for InspectLatest<S, G>
+where
+    S: Stream,
+    S::Item: Clone,
+    G: FnMut(Option<&S::Item>, &S::Item),
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let Some(x) = ready!(this.stream.poll_next(ctx)) else {
+            return Poll::Ready(None);
+        };
+        (this.g)(this.previous.as_ref(), &x);
+        *this.previous = Some(x.clone());
+        Poll::Ready(Some(x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, G> FusedStream for InspectLatest<S, G>
+where
+    S: Stream,
+    S::Item: Clone,
+    G: FnMut(Option<&S::Item>, &S::Item),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn previous_and_current_items_are_inspected() {
+        let mut seen = Vec::new();
+        let items = block_on(
+            stream::iter([1, 3, 6])
+                .inspect_latest(|prev, cur| seen.push((prev.copied(), *cur)))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(items, [1, 3, 6]);
+        assert_eq!(seen, [(None, 1), (Some(1), 3), (Some(3), 6)]);
+    }
+}
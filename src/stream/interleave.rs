@@ -0,0 +1,105 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::fuse::Fuse;
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::interleave`](crate::StreamTools::interleave).
+#[pin_project]
+#[derive(Clone, Debug)]
+pub struct Interleave<A, B> {
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    other_turn: bool,
+}
+
+impl<A, B> Interleave<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    pub(crate) fn new(stream: A, other_stream: B) -> Self {
+        Self {
+            stream: Fuse::new(stream),
+            other_stream: Fuse::new(other_stream),
+            other_turn: false,
+        }
+    }
+}
+
+impl<A, B> Stream for Interleave<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if this.stream.is_done() && this.other_stream.is_done() {
+                return Poll::Ready(None);
+            }
+            let other_turn = if this.stream.is_done() || this.other_stream.is_done() {
+                this.stream.is_done()
+            } else {
+                *this.other_turn
+            };
+            let item = if other_turn {
+                ready!(this.other_stream.as_mut().poll_next(ctx))
+            } else {
+                ready!(this.stream.as_mut().poll_next(ctx))
+            };
+            if let Some(item) = item {
+                *this.other_turn = !other_turn;
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let (other_lower, other_upper) = self.other_stream.size_hint();
+        let upper = upper
+            .zip(other_upper)
+            .and_then(|(upper, other_upper)| upper.checked_add(other_upper));
+        (lower.saturating_add(other_lower), upper)
+    }
+}
+
+impl<A, B> FusedStream for Interleave<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.other_stream.is_done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, Stream, StreamExt};
+
+    #[test]
+    fn items_alternate() {
+        let s = stream::iter([0, 2, 4]).interleave(stream::iter([1, 3, 5]));
+        assert_eq!(s.size_hint(), (6, Some(6)));
+        assert_eq!(block_on(s.collect::<Vec<_>>()), (0..6).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn remainder_is_drained_after_one_side_ends() {
+        let a = yield_on_none([None, Some(0), None, Some(2)]);
+        let b = stream::iter([1, 3, 4, 5]);
+        let actual = block_on(Box::pin(a.interleave(b)).collect::<Vec<_>>());
+        assert_eq!(actual, (0..6).collect::<Vec<_>>());
+    }
+}
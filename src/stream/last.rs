@@ -0,0 +1,86 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    future::FusedFuture,
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Future returned by [`StreamTools::last`](crate::StreamTools::last).
+#[pin_project]
+#[derive(Debug)]
+pub struct Last<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    last: Option<S::Item>,
+}
+
+impl<S> Last<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            last: None,
+        }
+    }
+}
+
+impl<S> Future for Last<S>
+where
+    S: Stream,
+{
+    type Output = Option<S::Item>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        while let Poll::Ready(x) = this.stream.as_mut().poll_next(ctx) {
+            match x {
+                Some(x) => *this.last = Some(x),
+                None => return Poll::Ready(this.last.take()),
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<S> FusedFuture for Last<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream};
+
+    #[test]
+    fn last_item_is_returned() {
+        assert_eq!(block_on(stream::iter(0..5).last()), Some(4));
+    }
+
+    #[test]
+    fn empty_stream_has_no_last_item() {
+        assert_eq!(block_on(stream::empty::<()>().last()), None);
+    }
+
+    #[test]
+    fn pending_items_are_awaited() {
+        let items = yield_on_none([Some(0), None, Some(1), None]);
+        assert_eq!(block_on(items.last()), Some(1));
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::latest_or`](crate::StreamTools::latest_or).
+#[pin_project]
+#[derive(Debug)]
+pub struct LatestOr<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    default: Option<S::Item>,
+}
+
+impl<S> LatestOr<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, default: S::Item) -> Self {
+        Self {
+            stream: stream.fuse(),
+            default: Some(default),
+        }
+    }
+}
+
+impl<S> Stream for LatestOr<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(ctx) {
+            Poll::Ready(x) => {
+                *this.default = None;
+                Poll::Ready(x)
+            }
+            Poll::Pending => match this.default.take() {
+                Some(default) => Poll::Ready(Some(default)),
+                None => Poll::Pending,
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let default = usize::from(self.default.is_some() && lower == 0);
+        (lower, upper.and_then(|upper| upper.checked_add(default)))
+    }
+}
+
+impl<S> FusedStream for LatestOr<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn default_is_yielded_once_while_stream_is_pending() {
+        let items = yield_on_none([None, None, Some(1), None, Some(2)]);
+        let actual = block_on(items.latest_or(0).collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1, 2]);
+    }
+
+    #[test]
+    fn default_is_skipped_if_stream_is_ready() {
+        let actual = block_on(stream::iter([1, 2]).latest_or(0).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 2]);
+    }
+}
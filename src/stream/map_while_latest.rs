@@ -0,0 +1,87 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::map_while_latest`](crate::StreamTools::map_while_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct MapWhileLatest<S, F> {
+    #[pin]
+    stream: S,
+    f: F,
+    done: bool,
+}
+
+impl<S, F, T> MapWhileLatest<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<T>,
+{
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<S, F, T> Stream for MapWhileLatest<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let item = ready!(this.stream.poll_next(ctx)).and_then(this.f);
+        *this.done = item.is_none();
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<S, F, T> FusedStream for MapWhileLatest<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        executor::block_on,
+        stream::{self, FusedStream},
+        StreamExt,
+    };
+
+    #[test]
+    fn mapping_stops_at_first_none() {
+        let strings = stream::iter(["1", "2", "x", "3"]);
+        let mut s = strings.map_while_latest(|s| s.parse::<i32>().ok());
+        let actual = block_on((&mut s).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 2]);
+        assert!(s.is_terminated());
+    }
+}
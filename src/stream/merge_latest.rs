@@ -0,0 +1,107 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::fuse::Fuse;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use either::Either;
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::merge_latest`](crate::StreamTools::merge_latest).
+#[pin_project]
+#[derive(Clone, Debug)]
+pub struct MergeLatest<A, B> {
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    other_first: bool,
+}
+
+impl<A, B> MergeLatest<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    pub(crate) fn new(stream: A, other_stream: B) -> Self {
+        Self {
+            stream: Fuse::new(stream),
+            other_stream: Fuse::new(other_stream),
+            other_first: false,
+        }
+    }
+}
+
+impl<A, B> Stream for MergeLatest<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    type Item = Either<A::Item, B::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let other_first = *this.other_first;
+        *this.other_first = !other_first;
+        for other in [other_first, !other_first] {
+            let item = if other {
+                this.other_stream
+                    .as_mut()
+                    .poll_next(ctx)
+                    .map(|x| x.map(Either::Right))
+            } else {
+                this.stream
+                    .as_mut()
+                    .poll_next(ctx)
+                    .map(|x| x.map(Either::Left))
+            };
+            if let Poll::Ready(Some(item)) = item {
+                return Poll::Ready(Some(item));
+            }
+        }
+        if this.stream.is_done() && this.other_stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<A, B> FusedStream for MergeLatest<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.other_stream.is_done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use either::Either::{Left, Right};
+    use futures::{channel::mpsc, executor::block_on, StreamExt};
+
+    #[test]
+    fn items_are_tagged_with_their_source_in_arrival_order() {
+        let (a_sender, a) = mpsc::unbounded();
+        let (b_sender, b) = mpsc::unbounded();
+        let mut merged = a.merge_latest(b);
+        block_on(async {
+            a_sender.unbounded_send(0).unwrap();
+            assert_eq!(merged.next().await, Some(Left(0)));
+            b_sender.unbounded_send('a').unwrap();
+            assert_eq!(merged.next().await, Some(Right('a')));
+            b_sender.unbounded_send('b').unwrap();
+            assert_eq!(merged.next().await, Some(Right('b')));
+            drop(b_sender);
+            a_sender.unbounded_send(1).unwrap();
+            assert_eq!(merged.next().await, Some(Left(1)));
+            drop(a_sender);
+            assert_eq!(merged.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::future::{yield_now, YieldNow};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::next_or_yield`](crate::StreamTools::next_or_yield).
+#[pin_project]
+#[derive(Debug)]
+pub struct NextOrYield<S> {
+    #[pin]
+    stream: Fuse<S>,
+    yield_now: YieldNow,
+}
+
+impl<S> NextOrYield<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            yield_now: yield_now(),
+        }
+    }
+}
+
+impl<S> Stream for NextOrYield<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(ctx) {
+            Poll::Ready(x) => {
+                this.yield_now.reset();
+                Poll::Ready(x)
+            }
+            Poll::Pending => {
+                let _ = Pin::new(this.yield_now).poll(ctx);
+                Poll::Pending
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S> FusedStream for NextOrYield<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{pending, FusedStream},
+        task::{waker, ArcWake},
+        Stream, StreamExt,
+    };
+    use std::{
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Context,
+    };
+
+    #[derive(Default)]
+    struct WakeCount(AtomicUsize);
+
+    impl ArcWake for WakeCount {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn all_items_are_yielded() {
+        let items = yield_on_none([Some(0), None, None, Some(1), None, Some(2)]);
+        let mut s = pin!(items.next_or_yield());
+        let actual = block_on(s.as_mut().collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1, 2]);
+        assert!(s.is_terminated());
+    }
+
+    #[test]
+    fn pending_stream_wakes_once() {
+        let mut s = pin!(pending::<()>().next_or_yield());
+        let count = Arc::new(WakeCount::default());
+        let waker = waker(count.clone());
+        let mut ctx = Context::from_waker(&waker);
+        for _ in 0..3 {
+            assert!(s.as_mut().poll_next(&mut ctx).is_pending());
+        }
+        assert_eq!(count.0.load(Ordering::Relaxed), 1);
+    }
+}
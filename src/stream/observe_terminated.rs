@@ -0,0 +1,85 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::sync::Arc;
+use core::{
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::observe_terminated`](crate::StreamTools::observe_terminated).
+#[pin_project]
+#[derive(Debug)]
+pub struct ObserveTerminated<S> {
+    #[pin]
+    stream: S,
+    terminated: Arc<AtomicBool>,
+}
+
+impl<S> ObserveTerminated<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S) -> (Self, Arc<AtomicBool>) {
+        let terminated = Arc::new(AtomicBool::new(false));
+        let stream = ObserveTerminated {
+            stream,
+            terminated: terminated.clone(),
+        };
+        (stream, terminated)
+    }
+}
+
+impl<S> Stream for ObserveTerminated<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if this.terminated.load(Ordering::Relaxed) {
+            return Poll::Ready(None);
+        }
+        let item = ready!(this.stream.poll_next(ctx));
+        if item.is_none() {
+            this.terminated.store(true, Ordering::Relaxed);
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated.load(Ordering::Relaxed) {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
+    }
+}
+
+impl<S> FusedStream for ObserveTerminated<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn flag_is_set_once_stream_ends() {
+        let (mut s, terminated) = stream::iter(0..3).observe_terminated();
+        assert_eq!(block_on((&mut s).take(3).collect::<Vec<_>>()), [0, 1, 2]);
+        assert!(!terminated.load(Ordering::Relaxed));
+        assert_eq!(block_on(s.next()), None);
+        assert!(terminated.load(Ordering::Relaxed));
+    }
+}
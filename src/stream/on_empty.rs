@@ -0,0 +1,86 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::on_empty`](crate::StreamTools::on_empty).
+#[pin_project]
+#[derive(Debug)]
+pub struct OnEmpty<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    default: Option<S::Item>,
+}
+
+impl<S> OnEmpty<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, default: S::Item) -> Self {
+        Self {
+            stream: stream.fuse(),
+            default: Some(default),
+        }
+    }
+}
+
+impl<S> Stream for OnEmpty<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match ready!(this.stream.poll_next(ctx)) {
+            Some(x) => {
+                *this.default = None;
+                Poll::Ready(Some(x))
+            }
+            None => Poll::Ready(this.default.take()),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let default = usize::from(self.default.is_some());
+        (lower.max(default), upper.map(|upper| upper.max(default)))
+    }
+}
+
+impl<S> FusedStream for OnEmpty<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.default.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn default_is_yielded_if_stream_is_empty() {
+        let actual = block_on(stream::empty().on_empty(0).collect::<Vec<_>>());
+        assert_eq!(actual, [0]);
+    }
+
+    #[test]
+    fn default_is_skipped_if_stream_is_not_empty() {
+        let actual = block_on(stream::iter([1, 2]).on_empty(0).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 2]);
+    }
+}
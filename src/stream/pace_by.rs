@@ -0,0 +1,144 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::pace_by`](crate::StreamTools::pace_by).
+#[pin_project]
+#[derive(Debug)]
+pub struct PaceBy<A, B, F>
+where
+    A: Stream,
+{
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    pacer: Fuse<B>,
+    latest: Option<A::Item>,
+    combine: F,
+}
+
+impl<A, B, F, T> PaceBy<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    pub(crate) fn new(stream: A, pacer: B, combine: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            pacer: pacer.fuse(),
+            latest: None,
+            combine,
+        }
+    }
+}
+
+impl<A, B, F, T> Stream for PaceBy<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let latest = &mut *this.latest;
+        let mut exhausted = drain_latest(this.stream.as_mut(), ctx, |x| *latest = Some(x));
+        if this.latest.is_none() && this.stream.is_done() {
+            return Poll::Ready(None);
+        }
+        let res = match &*this.latest {
+            Some(latest) => match this.pacer.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(tick)) => Poll::Ready(Some((this.combine)(latest, &tick))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            },
+            None => {
+                exhausted |= drain_latest(this.pacer.as_mut(), ctx, |_| {});
+                if this.pacer.is_done() {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+        };
+        if exhausted && res.is_pending() {
+            ctx.waker().wake_by_ref();
+        }
+        res
+    }
+}
+
+impl<A, B, F, T> FusedStream for PaceBy<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.pacer.is_done() || (self.latest.is_none() && self.stream.is_done())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{channel::mpsc, executor::block_on, poll, stream::repeat, StreamExt};
+
+    #[test]
+    fn one_item_per_pacer_tick() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (tick_sender, ticks) = mpsc::unbounded();
+        let mut paced = receiver.pace_by(ticks, |&x, &t| (x, t));
+        block_on(async {
+            sender.unbounded_send(0).unwrap();
+            sender.unbounded_send(1).unwrap();
+            tick_sender.unbounded_send('a').unwrap();
+            assert_eq!(paced.next().await, Some((1, 'a')));
+            sender.unbounded_send(2).unwrap();
+            sender.unbounded_send(3).unwrap();
+            sender.unbounded_send(4).unwrap();
+            tick_sender.unbounded_send('b').unwrap();
+            tick_sender.unbounded_send('c').unwrap();
+            assert_eq!(paced.next().await, Some((4, 'b')));
+            assert_eq!(paced.next().await, Some((4, 'c')));
+            drop(tick_sender);
+            assert_eq!(paced.next().await, None);
+        });
+    }
+
+    #[test]
+    fn ticks_before_first_item_are_skipped() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (tick_sender, ticks) = mpsc::unbounded();
+        let mut paced = receiver.pace_by(ticks, |&x: &i32, &t: &char| (x, t));
+        block_on(async {
+            tick_sender.unbounded_send('a').unwrap();
+            assert!(poll!(paced.next()).is_pending());
+            sender.unbounded_send(0).unwrap();
+            tick_sender.unbounded_send('b').unwrap();
+            assert_eq!(paced.next().await, Some((0, 'b')));
+        });
+    }
+
+    #[test]
+    fn always_ready_stream_is_paced() {
+        let ticks = yield_on_none([None, Some('a'), None, Some('b')]);
+        let actual = block_on(
+            repeat(1)
+                .pace_by(ticks, |&x, &t| (x, t))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [(1, 'a'), (1, 'b')]);
+    }
+}
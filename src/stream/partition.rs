@@ -0,0 +1,396 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use either::{Either, Left, Right};
+use futures::{stream::FusedStream, Stream};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// Stream of the left items returned by [`StreamTools::partition`](crate::StreamTools::partition).
+pub struct PartitionLeft<S, F, L, R>(Arc<Mutex<Shared<S, F, L, R>>>);
+
+/// Stream of the right items returned by [`StreamTools::partition`](crate::StreamTools::partition).
+pub struct PartitionRight<S, F, L, R>(Arc<Mutex<Shared<S, F, L, R>>>);
+
+/// Pair of streams returned by [`StreamTools::partition`](crate::StreamTools::partition).
+pub type Partition<S, F, L, R> = (PartitionLeft<S, F, L, R>, PartitionRight<S, F, L, R>);
+
+pub(crate) fn partition<S, F, L, R>(stream: S, f: F) -> Partition<S, F, L, R>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Either<L, R>,
+{
+    let shared = Arc::new(Mutex::new(Shared::new(stream, f)));
+    (PartitionLeft(shared.clone()), PartitionRight(shared))
+}
+
+impl<S, F, L, R> Debug for PartitionLeft<S, F, L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PartitionLeft")
+    }
+}
+
+impl<S, F, L, R> Debug for PartitionRight<S, F, L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PartitionRight")
+    }
+}
+
+impl<S, F, L, R> Stream for PartitionLeft<S, F, L, R>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Either<L, R>,
+{
+    type Item = L;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<L>> {
+        self.0.lock().unwrap().poll_left(ctx, |f, item| f(item))
+    }
+}
+
+impl<S, F, L, R> Stream for PartitionRight<S, F, L, R>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Either<L, R>,
+{
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<R>> {
+        self.0.lock().unwrap().poll_right(ctx, |f, item| f(item))
+    }
+}
+
+impl<S, F, L, R> FusedStream for PartitionLeft<S, F, L, R>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Either<L, R>,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.lock().unwrap().left_terminated()
+    }
+}
+
+impl<S, F, L, R> FusedStream for PartitionRight<S, F, L, R>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Either<L, R>,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.lock().unwrap().right_terminated()
+    }
+}
+
+impl<S, F, L, R> Drop for PartitionLeft<S, F, L, R> {
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.0.lock() {
+            shared.drop_left();
+        }
+    }
+}
+
+impl<S, F, L, R> Drop for PartitionRight<S, F, L, R> {
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.0.lock() {
+            shared.drop_right();
+        }
+    }
+}
+
+/// Stream of the items matching the predicate returned by
+/// [`StreamTools::partition_by`](crate::StreamTools::partition_by).
+pub struct PartitionByTrue<S, P>(Arc<Mutex<PartitionByShared<S, P>>>)
+where
+    S: Stream;
+
+/// Stream of the items not matching the predicate returned by
+/// [`StreamTools::partition_by`](crate::StreamTools::partition_by).
+pub struct PartitionByFalse<S, P>(Arc<Mutex<PartitionByShared<S, P>>>)
+where
+    S: Stream;
+
+/// Pair of streams returned by [`StreamTools::partition_by`](crate::StreamTools::partition_by).
+pub type PartitionBy<S, P> = (PartitionByTrue<S, P>, PartitionByFalse<S, P>);
+
+type PartitionByShared<S, P> = Shared<S, P, <S as Stream>::Item, <S as Stream>::Item>;
+
+pub(crate) fn partition_by<S, P>(stream: S, pred: P) -> PartitionBy<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    let shared = Arc::new(Mutex::new(Shared::new(stream, pred)));
+    (PartitionByTrue(shared.clone()), PartitionByFalse(shared))
+}
+
+fn route<T, P>(pred: &mut P, item: T) -> Either<T, T>
+where
+    P: FnMut(&T) -> bool,
+{
+    if pred(&item) {
+        Left(item)
+    } else {
+        Right(item)
+    }
+}
+
+impl<S, P> Debug for PartitionByTrue<S, P>
+where
+    S: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PartitionByTrue")
+    }
+}
+
+impl<S, P> Debug for PartitionByFalse<S, P>
+where
+    S: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PartitionByFalse")
+    }
+}
+
+impl<S, P> Stream for PartitionByTrue<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        self.0.lock().unwrap().poll_left(ctx, route)
+    }
+}
+
+impl<S, P> Stream for PartitionByFalse<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        self.0.lock().unwrap().poll_right(ctx, route)
+    }
+}
+
+impl<S, P> FusedStream for PartitionByTrue<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.lock().unwrap().left_terminated()
+    }
+}
+
+impl<S, P> FusedStream for PartitionByFalse<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.lock().unwrap().right_terminated()
+    }
+}
+
+impl<S, P> Drop for PartitionByTrue<S, P>
+where
+    S: Stream,
+{
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.0.lock() {
+            shared.drop_left();
+        }
+    }
+}
+
+impl<S, P> Drop for PartitionByFalse<S, P>
+where
+    S: Stream,
+{
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.0.lock() {
+            shared.drop_right();
+        }
+    }
+}
+
+struct Shared<S, F, L, R> {
+    stream: Pin<Box<S>>,
+    f: F,
+    done: bool,
+    left: Side<L>,
+    right: Side<R>,
+}
+
+impl<S, F, L, R> Shared<S, F, L, R> {
+    fn new(stream: S, f: F) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            f,
+            done: false,
+            left: Side::default(),
+            right: Side::default(),
+        }
+    }
+
+    fn left_terminated(&self) -> bool {
+        self.done && self.left.queue.is_empty()
+    }
+
+    fn right_terminated(&self) -> bool {
+        self.done && self.right.queue.is_empty()
+    }
+
+    fn drop_left(&mut self) {
+        self.left.dropped = true;
+        self.left.queue.clear();
+        self.right.wake();
+    }
+
+    fn drop_right(&mut self) {
+        self.right.dropped = true;
+        self.right.queue.clear();
+        self.left.wake();
+    }
+}
+
+impl<S, F, L, R> Shared<S, F, L, R>
+where
+    S: Stream,
+{
+    fn poll_left<G>(&mut self, ctx: &mut Context<'_>, mut route: G) -> Poll<Option<L>>
+    where
+        G: FnMut(&mut F, S::Item) -> Either<L, R>,
+    {
+        if let Some(item) = self.left.queue.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        loop {
+            match self.poll_stream(ctx, &mut route) {
+                Poll::Ready(Some(Left(item))) => break Poll::Ready(Some(item)),
+                Poll::Ready(Some(Right(item))) => self.right.push(item),
+                Poll::Ready(None) => break Poll::Ready(None),
+                Poll::Pending => {
+                    self.left.waker = Some(ctx.waker().clone());
+                    break Poll::Pending;
+                }
+            }
+        }
+    }
+
+    fn poll_right<G>(&mut self, ctx: &mut Context<'_>, mut route: G) -> Poll<Option<R>>
+    where
+        G: FnMut(&mut F, S::Item) -> Either<L, R>,
+    {
+        if let Some(item) = self.right.queue.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        loop {
+            match self.poll_stream(ctx, &mut route) {
+                Poll::Ready(Some(Left(item))) => self.left.push(item),
+                Poll::Ready(Some(Right(item))) => break Poll::Ready(Some(item)),
+                Poll::Ready(None) => break Poll::Ready(None),
+                Poll::Pending => {
+                    self.right.waker = Some(ctx.waker().clone());
+                    break Poll::Pending;
+                }
+            }
+        }
+    }
+
+    fn poll_stream<G>(&mut self, ctx: &mut Context<'_>, route: G) -> Poll<Option<Either<L, R>>>
+    where
+        G: FnOnce(&mut F, S::Item) -> Either<L, R>,
+    {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        match self.stream.as_mut().poll_next(ctx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(route(&mut self.f, item))),
+            Poll::Ready(None) => {
+                self.done = true;
+                self.left.wake();
+                self.right.wake();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+struct Side<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+impl<T> Side<T> {
+    fn push(&mut self, item: T) {
+        if !self.dropped {
+            self.queue.push_back(item);
+            self.wake();
+        }
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Default for Side<T> {
+    fn default() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            waker: None,
+            dropped: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use either::{Left, Right};
+    use futures::{executor::block_on, future::join, stream::iter, StreamExt};
+
+    #[test]
+    fn it_works() {
+        let (evens, odds) = iter(0..10).partition(|n| if n % 2 == 1 { Right(n) } else { Left(n) });
+        let (evens, odds) = block_on(join(evens.collect::<Vec<_>>(), odds.collect::<Vec<_>>()));
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn dropping_one_side_lets_other_drain() {
+        let (evens, odds) = iter(0..10).partition(|n| if n % 2 == 1 { Right(n) } else { Left(n) });
+        drop(evens);
+        let odds = block_on(odds.collect::<Vec<_>>());
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn partition_by_splits_by_predicate() {
+        let (evens, odds) = iter(0..10).partition_by(|n| n % 2 == 0);
+        let (evens, odds) = block_on(join(evens.collect::<Vec<_>>(), odds.collect::<Vec<_>>()));
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn dropping_one_side_of_partition_by_lets_other_drain() {
+        let (evens, odds) = iter(0..10).partition_by(|n| n % 2 == 0);
+        drop(odds);
+        let evens = block_on(evens.collect::<Vec<_>>());
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+    }
+}
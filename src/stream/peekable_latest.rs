@@ -0,0 +1,144 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::peekable_latest`](crate::StreamTools::peekable_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct PeekableLatest<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    peeked: Option<S::Item>,
+}
+
+impl<S> PeekableLatest<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            peeked: None,
+        }
+    }
+
+    /// Returns a future resolving to a reference to the next item without consuming it
+    ///
+    /// The future resolves to `None` if the stream has ended.
+    pub fn peek(&mut self) -> Peek<'_, S>
+    where
+        Self: Unpin,
+    {
+        Peek(Some(self))
+    }
+
+    /// Polls for the next item without consuming it
+    pub fn poll_peek(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<&S::Item>> {
+        let this = self.project();
+        if this.peeked.is_none() {
+            *this.peeked = ready!(this.stream.poll_next(ctx));
+        }
+        Poll::Ready(this.peeked.as_ref())
+    }
+}
+
+impl<S> Stream for PeekableLatest<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.peeked.take() {
+            Some(x) => Poll::Ready(Some(x)),
+            None => this.stream.poll_next(ctx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let n = usize::from(self.peeked.is_some());
+        (
+            lower.saturating_add(n),
+            upper.and_then(|upper| upper.checked_add(n)),
+        )
+    }
+}
+
+impl<S> FusedStream for PeekableLatest<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.peeked.is_none()
+    }
+}
+
+/// Future returned by [`PeekableLatest::peek`]
+pub struct Peek<'a, S>(Option<&'a mut PeekableLatest<S>>)
+where
+    S: Stream;
+
+impl<S> Debug for Peek<'_, S>
+where
+    S: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Peek")
+    }
+}
+
+impl<'a, S> Future for Peek<'a, S>
+where
+    S: Stream,
+    PeekableLatest<S>: Unpin,
+{
+    type Output = Option<&'a S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.0.take().expect("Peek polled after completion");
+        match Pin::new(&mut *inner).poll_peek(ctx) {
+            Poll::Ready(_) => Poll::Ready(inner.peeked.as_ref()),
+            Poll::Pending => {
+                self.0 = Some(inner);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn peeked_item_is_stable() {
+        let mut s = stream::iter(0..3).peekable_latest();
+        assert_eq!(block_on(s.peek()), Some(&0));
+        assert_eq!(block_on(s.peek()), Some(&0));
+        assert_eq!(block_on(s.collect::<Vec<_>>()), [0, 1, 2]);
+    }
+
+    #[test]
+    fn pending_items_can_be_peeked() {
+        let mut s = Box::pin(yield_on_none([None, Some(0)])).peekable_latest();
+        assert_eq!(block_on(s.peek()), Some(&0));
+        assert_eq!(block_on(s.next()), Some(0));
+        assert_eq!(block_on(s.peek()), None);
+    }
+}
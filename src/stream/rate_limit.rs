@@ -0,0 +1,141 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// What to do with an item received while the buffer of a [`RateLimit`] stream is full
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Overflow {
+    /// Drops the oldest buffered item to make room for the new one
+    DropOldest,
+    /// Drops the new item
+    DropNewest,
+}
+
+/// Stream returned by [`StreamTools::rate_limit`](crate::StreamTools::rate_limit).
+#[pin_project]
+#[derive(Debug)]
+pub struct RateLimit<S, P>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    permits: Fuse<P>,
+    queue: VecDeque<S::Item>,
+    capacity: usize,
+    overflow: Overflow,
+    done: bool,
+}
+
+impl<S, P> RateLimit<S, P>
+where
+    S: Stream,
+    P: Stream<Item = ()>,
+{
+    pub(crate) fn new(stream: S, permits: P, capacity: usize, overflow: Overflow) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            stream: stream.fuse(),
+            permits: permits.fuse(),
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+            overflow,
+            done: false,
+        }
+    }
+}
+
+impl<S, P> Stream for RateLimit<S, P>
+where
+    S: Stream,
+    P: Stream<Item = ()>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        for _ in 0..*this.capacity {
+            let Poll::Ready(Some(x)) = this.stream.as_mut().poll_next(ctx) else {
+                break;
+            };
+            if this.queue.len() < *this.capacity {
+                this.queue.push_back(x);
+            } else if *this.overflow == Overflow::DropOldest {
+                this.queue.pop_front();
+                this.queue.push_back(x);
+            }
+        }
+        if this.queue.is_empty() {
+            *this.done = this.stream.is_done();
+            return if *this.done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+        match this.permits.poll_next(ctx) {
+            Poll::Ready(Some(())) => Poll::Ready(this.queue.pop_front()),
+            Poll::Ready(None) => {
+                *this.done = true;
+                this.queue.clear();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S, P> FusedStream for RateLimit<S, P>
+where
+    S: Stream,
+    P: Stream<Item = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stream::{test_util::yield_on_none, Overflow},
+        StreamTools,
+    };
+    use futures::{executor::block_on, stream, StreamExt};
+
+    fn permits() -> impl futures::Stream<Item = ()> {
+        yield_on_none([None, Some(()), Some(()), Some(()), Some(()), Some(())])
+    }
+
+    #[test]
+    fn oldest_items_are_dropped_on_overflow() {
+        let limited = stream::iter(0..5).rate_limit(permits(), 2, Overflow::DropOldest);
+        assert_eq!(block_on(limited.collect::<Vec<_>>()), [2, 3, 4]);
+    }
+
+    #[test]
+    fn newest_items_are_dropped_on_overflow() {
+        let limited = stream::iter(0..5).rate_limit(permits(), 2, Overflow::DropNewest);
+        assert_eq!(block_on(limited.collect::<Vec<_>>()), [0, 1, 4]);
+    }
+
+    #[test]
+    fn stream_ends_when_permits_run_out() {
+        let limited =
+            stream::iter(0..5).rate_limit(stream::iter([(), ()]), 8, Overflow::DropNewest);
+        assert_eq!(block_on(limited.collect::<Vec<_>>()), [0, 1]);
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::repeat_latest`](crate::StreamTools::repeat_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct RepeatLatest<S, T>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    trigger: Fuse<T>,
+    latest: Option<S::Item>,
+}
+
+impl<S, T> RepeatLatest<S, T>
+where
+    S: Stream,
+    S::Item: Clone,
+    T: Stream,
+{
+    pub(crate) fn new(stream: S, trigger: T) -> Self {
+        Self {
+            stream: stream.fuse(),
+            trigger: trigger.fuse(),
+            latest: None,
+        }
+    }
+}
+
+impl<S, T> Stream for RepeatLatest<S, T>
+where
+    S: Stream,
+    S::Item: Clone,
+    T: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if let Poll::Ready(Some(x)) = this.stream.as_mut().poll_next(ctx) {
+            *this.latest = Some(x.clone());
+            return Poll::Ready(Some(x));
+        }
+        let mut exhausted = false;
+        match this.latest {
+            Some(x) => {
+                if let Poll::Ready(Some(_)) = this.trigger.as_mut().poll_next(ctx) {
+                    return Poll::Ready(Some(x.clone()));
+                }
+            }
+            None => exhausted = drain_latest(this.trigger.as_mut(), ctx, |_| {}),
+        }
+        if this.stream.is_terminated() && this.trigger.is_terminated() {
+            Poll::Ready(None)
+        } else {
+            if exhausted {
+                ctx.waker().wake_by_ref();
+            }
+            Poll::Pending
+        }
+    }
+}
+
+impl<S, T> FusedStream for RepeatLatest<S, T>
+where
+    S: Stream,
+    S::Item: Clone,
+    T: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.trigger.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        channel::mpsc,
+        executor::block_on,
+        poll,
+        stream::{pending, repeat},
+        task::noop_waker_ref,
+        StreamExt,
+    };
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn trigger_replays_latest_item() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (trigger_sender, trigger) = mpsc::unbounded();
+        let mut repeated = receiver.repeat_latest(trigger);
+        block_on(async {
+            trigger_sender.unbounded_send(()).unwrap();
+            assert!(poll!(repeated.next()).is_pending());
+            sender.unbounded_send(1).unwrap();
+            assert_eq!(repeated.next().await, Some(1));
+            trigger_sender.unbounded_send(()).unwrap();
+            trigger_sender.unbounded_send(()).unwrap();
+            assert_eq!(repeated.next().await, Some(1));
+            assert_eq!(repeated.next().await, Some(1));
+            sender.unbounded_send(2).unwrap();
+            drop(sender);
+            assert_eq!(repeated.next().await, Some(2));
+            trigger_sender.unbounded_send(()).unwrap();
+            assert_eq!(repeated.next().await, Some(2));
+            drop(trigger_sender);
+            assert_eq!(repeated.next().await, None);
+        });
+    }
+
+    #[test]
+    fn always_ready_trigger_does_not_block_before_first_item() {
+        let mut repeated = pending::<i32>().repeat_latest(repeat(()));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(repeated.poll_next_unpin(&mut ctx), Poll::Pending);
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::future::{yield_now, YieldNow};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+
+/// Stream returned by [`repeat_with_yield`](crate::stream::repeat_with_yield).
+#[derive(Debug)]
+pub struct RepeatWithYield<F> {
+    f: F,
+    yield_now: YieldNow,
+    must_yield: bool,
+}
+
+impl<F, T> RepeatWithYield<F>
+where
+    F: FnMut() -> T,
+{
+    pub(crate) fn new(f: F) -> Self {
+        Self {
+            f,
+            yield_now: yield_now(),
+            must_yield: false,
+        }
+    }
+}
+
+impl<F> Unpin for RepeatWithYield<F> {}
+
+impl<F, T> Stream for RepeatWithYield<F>
+where
+    F: FnMut() -> T,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        if this.must_yield {
+            ready!(Pin::new(&mut this.yield_now).poll(ctx));
+            this.yield_now.reset();
+            this.must_yield = false;
+        }
+        this.must_yield = true;
+        Poll::Ready(Some((this.f)()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<F, T> FusedStream for RepeatWithYield<F>
+where
+    F: FnMut() -> T,
+{
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::repeat_with_yield;
+    use futures::{stream::FusedStream, task::noop_waker_ref, StreamExt};
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn executor_is_yielded_to_between_items() {
+        let mut n = 0;
+        let mut items = repeat_with_yield(|| {
+            n += 1;
+            n
+        });
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(items.poll_next_unpin(&mut ctx), Poll::Ready(Some(1)));
+        for i in 2..5 {
+            assert_eq!(items.poll_next_unpin(&mut ctx), Poll::Pending);
+            assert_eq!(items.poll_next_unpin(&mut ctx), Poll::Ready(Some(i)));
+        }
+        assert!(!items.is_terminated());
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream, TryStream};
+use pin_project::pin_project;
+
+/// Stream returned by [`retry_stream`](crate::stream::retry_stream).
+#[pin_project]
+#[derive(Debug)]
+pub struct RetryStream<F, S> {
+    factory: F,
+    #[pin]
+    stream: Option<S>,
+    retries_left: usize,
+}
+
+impl<F, S> RetryStream<F, S>
+where
+    F: FnMut() -> S,
+    S: TryStream,
+{
+    pub(crate) fn new(mut factory: F, max: usize) -> Self {
+        let stream = factory();
+        Self {
+            factory,
+            stream: Some(stream),
+            retries_left: max,
+        }
+    }
+}
+
+impl<F, S> Stream for RetryStream<F, S>
+where
+    F: FnMut() -> S,
+    S: TryStream,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+                return Poll::Ready(None);
+            };
+            match ready!(stream.try_poll_next(ctx)) {
+                Some(Err(_)) if *this.retries_left > 0 => {
+                    *this.retries_left -= 1;
+                    this.stream.set(Some((this.factory)()));
+                }
+                Some(Err(e)) => {
+                    this.stream.set(None);
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Some(Ok(x)) => return Poll::Ready(Some(Ok(x))),
+                None => {
+                    this.stream.set(None);
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
+
+impl<F, S> FusedStream for RetryStream<F, S>
+where
+    F: FnMut() -> S,
+    S: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::retry_stream;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn stream_is_recreated_after_error() {
+        let factory = || stream::iter([Ok(0), Ok(1), Err("disconnected")]);
+        let actual = block_on(retry_stream(factory, 1).collect::<Vec<_>>());
+        assert_eq!(actual, [Ok(0), Ok(1), Ok(0), Ok(1), Err("disconnected")]);
+    }
+}
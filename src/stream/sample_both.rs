@@ -0,0 +1,136 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::sample_both`](crate::StreamTools::sample_both).
+#[pin_project]
+#[derive(Debug)]
+pub struct SampleBoth<A, B, T>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    #[pin]
+    trigger: Fuse<T>,
+    latest: Option<A::Item>,
+    other_latest: Option<B::Item>,
+}
+
+impl<A, B, T> SampleBoth<A, B, T>
+where
+    A: Stream,
+    B: Stream,
+    T: Stream,
+{
+    pub(crate) fn new(stream: A, other_stream: B, trigger: T) -> Self {
+        Self {
+            stream: stream.fuse(),
+            other_stream: other_stream.fuse(),
+            trigger: trigger.fuse(),
+            latest: None,
+            other_latest: None,
+        }
+    }
+}
+
+impl<A, B, T> Stream for SampleBoth<A, B, T>
+where
+    A: Stream,
+    B: Stream,
+    T: Stream,
+{
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let latest = &mut *this.latest;
+        let other_latest = &mut *this.other_latest;
+        let exhausted = drain_latest(this.stream.as_mut(), ctx, |x| *latest = Some(x))
+            | drain_latest(this.other_stream.as_mut(), ctx, |x| *other_latest = Some(x));
+        match this.trigger.poll_next(ctx) {
+            Poll::Ready(Some(_)) => {
+                Poll::Ready(Some((this.latest.take(), this.other_latest.take())))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => {
+                if exhausted {
+                    ctx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<A, B, T> FusedStream for SampleBoth<A, B, T>
+where
+    A: Stream,
+    B: Stream,
+    T: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.trigger.is_done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use core::task::Poll;
+    use futures::{
+        channel::mpsc,
+        executor::block_on,
+        poll,
+        stream::{iter, repeat},
+        SinkExt, StreamExt,
+    };
+
+    #[test]
+    fn advancing_sides_are_reported() {
+        block_on(async {
+            let (mut a_tx, a_rx) = mpsc::unbounded();
+            let (mut b_tx, b_rx) = mpsc::unbounded();
+            let (mut tick_tx, tick_rx) = mpsc::unbounded();
+            let mut sampled = a_rx.sample_both(b_rx, tick_rx);
+            assert_eq!(poll!(sampled.next()), Poll::Pending);
+            a_tx.send(0).await.unwrap();
+            a_tx.send(1).await.unwrap();
+            tick_tx.send(()).await.unwrap();
+            assert_eq!(poll!(sampled.next()), Poll::Ready(Some((Some(1), None))));
+            b_tx.send(10).await.unwrap();
+            tick_tx.send(()).await.unwrap();
+            assert_eq!(poll!(sampled.next()), Poll::Ready(Some((None, Some(10)))));
+            a_tx.send(2).await.unwrap();
+            b_tx.send(11).await.unwrap();
+            tick_tx.send(()).await.unwrap();
+            assert_eq!(
+                poll!(sampled.next()),
+                Poll::Ready(Some((Some(2), Some(11))))
+            );
+            tick_tx.send(()).await.unwrap();
+            assert_eq!(poll!(sampled.next()), Poll::Ready(Some((None, None))));
+            drop(tick_tx);
+            assert_eq!(poll!(sampled.next()), Poll::Ready(None));
+        });
+    }
+
+    #[test]
+    fn always_ready_side_does_not_prevent_sampling() {
+        let ticks = yield_on_none([None, Some(()), None, Some(())]);
+        let actual = block_on(repeat(1).sample_both(iter([10]), ticks).collect::<Vec<_>>());
+        assert_eq!(actual, [(Some(1), Some(10)), (Some(1), None)]);
+    }
+}
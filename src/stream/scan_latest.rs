@@ -0,0 +1,83 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::scan_latest`](crate::StreamTools::scan_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct ScanLatest<S, St, F> {
+    #[pin]
+    stream: S,
+    state: St,
+    f: F,
+}
+
+impl<S, St, F, T> ScanLatest<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> Option<T>,
+{
+    pub(crate) fn new(stream: S, init: St, f: F) -> Self {
+        Self {
+            stream,
+            state: init,
+            f,
+        }
+    }
+}
+
+impl<S, St, F, T> Stream for ScanLatest<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(x) = ready!(this.stream.as_mut().poll_next(ctx)) else {
+                return Poll::Ready(None);
+            };
+            if let Some(y) = (this.f)(this.state, x) {
+                return Poll::Ready(Some(y));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<S, St, F, T> FusedStream for ScanLatest<S, St, F>
+where
+    S: FusedStream,
+    F: FnMut(&mut St, S::Item) -> Option<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn skipped_items_do_not_end_stream() {
+        let sums = stream::iter([1, 2, -5, 3, -1, 4]).scan_latest(0, |sum, n| {
+            (n >= 0).then(|| {
+                *sum += n;
+                *sum
+            })
+        });
+        assert_eq!(block_on(sums.collect::<Vec<_>>()), [1, 3, 6, 10]);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::future::{yield_now, YieldNow};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::scan_with_yield`](crate::StreamTools::scan_with_yield).
+#[pin_project]
+#[derive(Debug)]
+pub struct ScanWithYield<S, St, F> {
+    #[pin]
+    stream: Fuse<S>,
+    state: St,
+    f: F,
+    yield_every: usize,
+    processed: usize,
+    yield_now: YieldNow,
+}
+
+impl<S, St, F, T> ScanWithYield<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> T,
+{
+    pub(crate) fn new(stream: S, init: St, f: F, yield_every: usize) -> Self {
+        Self {
+            stream: stream.fuse(),
+            state: init,
+            f,
+            yield_every: yield_every.max(1),
+            processed: 0,
+            yield_now: yield_now(),
+        }
+    }
+}
+
+impl<S, St, F, T> Stream for ScanWithYield<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut this = self.project();
+        if *this.processed >= *this.yield_every {
+            ready!(Pin::new(&mut *this.yield_now).poll(ctx));
+            this.yield_now.reset();
+            *this.processed = 0;
+        }
+        let Some(x) = ready!(this.stream.as_mut().poll_next(ctx)) else {
+            return Poll::Ready(None);
+        };
+        *this.processed += 1;
+        Poll::Ready(Some((this.f)(this.state, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, St, F, T> FusedStream for ScanWithYield<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{stream, task::noop_waker_ref, Stream};
+    use std::{
+        pin::pin,
+        task::{Context, Poll},
+    };
+
+    #[test]
+    fn stream_yields_after_every_n_items() {
+        let mut scanned = pin!(stream::iter(0..5).scan_with_yield(
+            0,
+            |sum, x| {
+                *sum += x;
+                *sum
+            },
+            2
+        ));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        let polls = (0..8)
+            .map(|_| scanned.as_mut().poll_next(&mut ctx))
+            .collect::<Vec<_>>();
+        let expected = [
+            Poll::Ready(Some(0)),
+            Poll::Ready(Some(1)),
+            Poll::Pending,
+            Poll::Ready(Some(3)),
+            Poll::Ready(Some(6)),
+            Poll::Pending,
+            Poll::Ready(Some(10)),
+            Poll::Ready(None),
+        ];
+        assert_eq!(polls, expected);
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{future::FusedFuture, Stream, StreamExt};
+
+/// Future returned by [`StreamTools::split_first`](crate::StreamTools::split_first).
+#[derive(Debug)]
+pub struct SplitFirst<S>(Option<S>);
+
+impl<S> SplitFirst<S>
+where
+    S: Stream + Unpin,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Self(Some(stream))
+    }
+}
+
+impl<S> Future for SplitFirst<S>
+where
+    S: Stream + Unpin,
+{
+    type Output = (Option<S::Item>, S);
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let stream = self.0.as_mut().expect("SplitFirst polled after completion");
+        let first = ready!(stream.poll_next_unpin(ctx));
+        let stream = self.0.take().expect("SplitFirst polled after completion");
+        Poll::Ready((first, stream))
+    }
+}
+
+impl<S> FusedFuture for SplitFirst<S>
+where
+    S: Stream + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn first_item_and_rest_are_returned() {
+        let (first, rest) = block_on(stream::iter(0..3).split_first());
+        assert_eq!(first, Some(0));
+        assert_eq!(block_on(rest.collect::<Vec<_>>()), [1, 2]);
+    }
+
+    #[test]
+    fn empty_stream_has_no_first_item() {
+        let (first, _) = block_on(stream::empty::<()>().split_first());
+        assert_eq!(first, None);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::start_with`](crate::StreamTools::start_with).
+#[pin_project]
+#[derive(Debug)]
+pub struct StartWith<S, I> {
+    #[pin]
+    stream: S,
+    items: Option<I>,
+}
+
+impl<S, I> StartWith<S, I>
+where
+    S: Stream,
+    I: Iterator<Item = S::Item>,
+{
+    pub(crate) fn new(stream: S, items: I) -> Self {
+        Self {
+            stream,
+            items: Some(items),
+        }
+    }
+}
+
+impl<S, I> Stream for StartWith<S, I>
+where
+    S: Stream,
+    I: Iterator<Item = S::Item>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if let Some(items) = this.items {
+            match items.next() {
+                Some(item) => return Poll::Ready(Some(item)),
+                None => *this.items = None,
+            }
+        }
+        this.stream.poll_next(ctx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.stream.size_hint();
+        match &self.items {
+            Some(items) => {
+                let (items_low, items_high) = items.size_hint();
+                (
+                    low.saturating_add(items_low),
+                    high.zip(items_high).and_then(|(a, b)| a.checked_add(b)),
+                )
+            }
+            None => (low, high),
+        }
+    }
+}
+
+impl<S, I> FusedStream for StartWith<S, I>
+where
+    S: FusedStream,
+    I: Iterator<Item = S::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.items.is_none() && self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream::iter, Stream, StreamExt};
+
+    #[test]
+    fn it_works() {
+        let s = iter(0..3).start_with([-1, -2]);
+        assert_eq!(s.size_hint(), (5, Some(5)));
+        let actual = block_on(s.collect::<Vec<_>>());
+        assert_eq!(actual, [-1, -2, 0, 1, 2]);
+    }
+}
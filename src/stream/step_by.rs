@@ -0,0 +1,104 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::step_by`](crate::StreamTools::step_by).
+#[pin_project]
+#[derive(Debug)]
+pub struct StepBy<S> {
+    #[pin]
+    stream: Fuse<S>,
+    step: usize,
+    skip: usize,
+}
+
+impl<S> StepBy<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, step: usize) -> Self {
+        assert!(step > 0, "step must be positive");
+        Self {
+            stream: stream.fuse(),
+            step,
+            skip: 0,
+        }
+    }
+}
+
+impl<S> Stream for StepBy<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(ctx)) {
+                Some(_) if *this.skip > 0 => *this.skip -= 1,
+                Some(x) => {
+                    *this.skip = *this.step - 1;
+                    return Poll::Ready(Some(x));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let steps = |n: usize| n.saturating_sub(self.skip).div_ceil(self.step);
+        (steps(lower), upper.map(steps))
+    }
+}
+
+impl<S> FusedStream for StepBy<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream, Stream, StreamExt};
+
+    #[test]
+    fn every_nth_item_is_yielded() {
+        let actual = block_on(stream::iter(0..10).step_by(3).collect::<Vec<_>>());
+        assert_eq!(actual, [0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn step_of_one_yields_everything() {
+        let items = yield_on_none([Some(0), None, Some(1), Some(2)]);
+        let actual = block_on(items.step_by(1).collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1, 2]);
+    }
+
+    #[test]
+    fn size_hint_is_divided() {
+        let mut stepped = stream::iter(0..10).step_by(3);
+        assert_eq!(stepped.size_hint(), (4, Some(4)));
+        assert_eq!(block_on(stepped.next()), Some(0));
+        assert_eq!(stepped.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn zero_step_panics() {
+        let _ = stream::iter(0..10).step_by(0);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::take_until_done`](crate::StreamTools::take_until_done).
+#[pin_project]
+#[derive(Debug)]
+pub struct TakeUntilDone<S, Fut> {
+    #[pin]
+    stream: S,
+    #[pin]
+    until: Fut,
+    done: bool,
+}
+
+impl<S, Fut> TakeUntilDone<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    pub(crate) fn new(stream: S, until: Fut) -> Self {
+        Self {
+            stream,
+            until,
+            done: false,
+        }
+    }
+}
+
+impl<S, Fut> Stream for TakeUntilDone<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        if this.until.poll(ctx).is_ready() {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+        let res = this.stream.poll_next(ctx);
+        *this.done = matches!(res, Poll::Ready(None));
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<S, Fut> FusedStream for TakeUntilDone<S, Fut>
+where
+    S: Stream,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{future::yield_n, stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream::FusedStream, StreamExt};
+    use std::pin::pin;
+
+    #[test]
+    fn items_after_cutoff_are_dropped() {
+        let a = yield_on_none([Some(0), Some(1), None, None, Some(2)]);
+        let mut s = pin!(a.take_until_done(yield_n(3)));
+        let actual = block_on(s.as_mut().collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1]);
+        assert!(s.is_terminated());
+    }
+}
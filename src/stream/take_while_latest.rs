@@ -0,0 +1,105 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::take_while_latest`](crate::StreamTools::take_while_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct TakeWhileLatest<S, P> {
+    #[pin]
+    stream: S,
+    pred: P,
+    include_last: bool,
+    done: bool,
+}
+
+impl<S, P> TakeWhileLatest<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    pub(crate) fn new(stream: S, pred: P, include_last: bool) -> Self {
+        Self {
+            stream,
+            pred,
+            include_last,
+            done: false,
+        }
+    }
+}
+
+impl<S, P> Stream for TakeWhileLatest<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let item = ready!(this.stream.poll_next(ctx));
+        match item {
+            Some(item) if (this.pred)(&item) => Poll::Ready(Some(item)),
+            Some(item) => {
+                *this.done = true;
+                Poll::Ready(Some(item).filter(|_| *this.include_last))
+            }
+            None => {
+                *this.done = true;
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<S, P> FusedStream for TakeWhileLatest<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{
+        executor::block_on,
+        stream::{self, FusedStream},
+        StreamExt,
+    };
+
+    #[test]
+    fn failing_item_is_yielded_when_inclusive() {
+        let mut s = stream::iter(0..10).take_while_latest(|&n| n < 3, true);
+        let actual = block_on((&mut s).collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1, 2, 3]);
+        assert!(s.is_terminated());
+    }
+
+    #[test]
+    fn failing_item_is_dropped_when_exclusive() {
+        let mut s = stream::iter(0..10).take_while_latest(|&n| n < 3, false);
+        let actual = block_on((&mut s).collect::<Vec<_>>());
+        assert_eq!(actual, [0, 1, 2]);
+        assert!(s.is_terminated());
+    }
+}
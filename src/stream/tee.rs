@@ -0,0 +1,113 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Sink, Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::tee`](crate::StreamTools::tee).
+#[pin_project]
+#[derive(Debug)]
+pub struct Tee<S, K>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    sink: K,
+    item: Option<S::Item>,
+    sent: bool,
+    sink_done: bool,
+}
+
+impl<S, K> Tee<S, K>
+where
+    S: Stream,
+    S::Item: Clone,
+    K: Sink<S::Item>,
+{
+    pub(crate) fn new(stream: S, sink: K) -> Self {
+        Self {
+            stream: stream.fuse(),
+            sink,
+            item: None,
+            sent: false,
+            sink_done: false,
+        }
+    }
+}
+
+impl<S, K> Stream for Tee<S, K>
+where
+    S: Stream,
+    S::Item: Clone,
+    K: Sink<S::Item>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if this.item.is_none() && !this.stream.is_done() {
+            *this.item = ready!(this.stream.as_mut().poll_next(ctx));
+            *this.sent = false;
+        }
+        if !*this.sink_done {
+            let res = match this.item {
+                Some(item) => ready!(deliver(this.sink, item, this.sent, ctx)),
+                None => ready!(this.sink.poll_close(ctx)),
+            };
+            *this.sink_done = res.is_err() || this.item.is_none();
+        }
+        Poll::Ready(this.item.take())
+    }
+}
+
+fn deliver<K, T>(
+    mut sink: Pin<&mut K>,
+    item: &T,
+    sent: &mut bool,
+    ctx: &mut Context<'_>,
+) -> Poll<Result<(), K::Error>>
+where
+    K: Sink<T>,
+    T: Clone,
+{
+    if !*sent {
+        ready!(sink.as_mut().poll_ready(ctx)?);
+        sink.as_mut().start_send(item.clone())?;
+        *sent = true;
+    }
+    sink.poll_flush(ctx)
+}
+
+impl<S, K> FusedStream for Tee<S, K>
+where
+    S: Stream,
+    S::Item: Clone,
+    K: Sink<S::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.item.is_none() && self.sink_done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{channel::mpsc, executor::block_on, stream::iter, StreamExt};
+
+    #[test]
+    fn items_are_copied_into_sink() {
+        let (sender, receiver) = mpsc::unbounded();
+        let output = block_on(iter(0..5).tee(sender).collect::<Vec<_>>());
+        let copies = block_on(receiver.collect::<Vec<_>>());
+        assert_eq!(output, [0, 1, 2, 3, 4]);
+        assert_eq!(copies, output);
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::throttle_first`](crate::StreamTools::throttle_first).
+#[pin_project]
+#[derive(Debug)]
+pub struct ThrottleFirst<S, W> {
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    window: Fuse<W>,
+    open: bool,
+}
+
+impl<S, W> ThrottleFirst<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    pub(crate) fn new(stream: S, window: W) -> Self {
+        Self {
+            stream: stream.fuse(),
+            window: window.fuse(),
+            open: true,
+        }
+    }
+}
+
+impl<S, W> Stream for ThrottleFirst<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let open = &mut *this.open;
+        let mut exhausted = drain_latest(this.window.as_mut(), ctx, |_| *open = true);
+        let res = if *this.open {
+            let res = this.stream.as_mut().poll_next(ctx);
+            if let Poll::Ready(Some(_)) = res {
+                *this.open = false;
+            }
+            res
+        } else {
+            exhausted |= drain_latest(this.stream.as_mut(), ctx, |_| {});
+            if this.stream.is_done() {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            }
+        };
+        if exhausted && res.is_pending() {
+            ctx.waker().wake_by_ref();
+        }
+        res
+    }
+}
+
+impl<S, W> FusedStream for ThrottleFirst<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{channel::mpsc, executor::block_on, poll, stream::repeat, StreamExt};
+
+    #[test]
+    fn bursts_collapse_to_first_item_per_window() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (window_sender, window) = mpsc::unbounded();
+        let mut throttled = receiver.throttle_first(window);
+        block_on(async {
+            sender.unbounded_send(0).unwrap();
+            sender.unbounded_send(1).unwrap();
+            sender.unbounded_send(2).unwrap();
+            assert_eq!(throttled.next().await, Some(0));
+            assert!(poll!(throttled.next()).is_pending());
+            window_sender.unbounded_send(()).unwrap();
+            sender.unbounded_send(3).unwrap();
+            sender.unbounded_send(4).unwrap();
+            assert_eq!(throttled.next().await, Some(3));
+            assert!(poll!(throttled.next()).is_pending());
+            window_sender.unbounded_send(()).unwrap();
+            window_sender.unbounded_send(()).unwrap();
+            sender.unbounded_send(5).unwrap();
+            assert_eq!(throttled.next().await, Some(5));
+            sender.unbounded_send(6).unwrap();
+            drop(sender);
+            assert_eq!(throttled.next().await, None);
+        });
+    }
+
+    #[test]
+    fn always_ready_stream_is_throttled() {
+        let window = yield_on_none([None, Some(()), None, Some(())]);
+        let actual = block_on(repeat(1).throttle_first(window).take(3).collect::<Vec<_>>());
+        assert_eq!(actual, [1, 1, 1]);
+    }
+
+    #[test]
+    fn always_ready_window_does_not_prevent_yielding() {
+        let actual = block_on(
+            repeat(1)
+                .throttle_first(repeat(()))
+                .take(3)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [1, 1, 1]);
+    }
+}
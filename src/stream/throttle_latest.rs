@@ -0,0 +1,134 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::drain_latest;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::throttle_latest`](crate::StreamTools::throttle_latest).
+#[pin_project]
+#[derive(Debug)]
+pub struct ThrottleLatest<S, W>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    window: Fuse<W>,
+    pending: Option<S::Item>,
+}
+
+impl<S, W> ThrottleLatest<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    pub(crate) fn new(stream: S, window: W) -> Self {
+        Self {
+            stream: stream.fuse(),
+            window: window.fuse(),
+            pending: None,
+        }
+    }
+}
+
+impl<S, W> Stream for ThrottleLatest<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let pending = &mut *this.pending;
+        let mut exhausted = drain_latest(this.stream.as_mut(), ctx, |x| *pending = Some(x));
+        if this.stream.is_terminated() {
+            return Poll::Ready(this.pending.take());
+        }
+        if this.pending.is_none() {
+            exhausted |= drain_latest(this.window.as_mut(), ctx, |_| {});
+        } else if let Poll::Ready(Some(_)) = this.window.as_mut().poll_next(ctx) {
+            return Poll::Ready(this.pending.take());
+        }
+        if exhausted {
+            ctx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+}
+
+impl<S, W> FusedStream for ThrottleLatest<S, W>
+where
+    S: Stream,
+    W: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.pending.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        channel::mpsc,
+        executor::block_on,
+        poll,
+        stream::{pending, repeat},
+        task::noop_waker_ref,
+        StreamExt,
+    };
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn bursts_collapse_to_last_item_per_window() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (window_sender, window) = mpsc::unbounded();
+        let mut throttled = receiver.throttle_latest(window);
+        block_on(async {
+            sender.unbounded_send(0).unwrap();
+            sender.unbounded_send(1).unwrap();
+            sender.unbounded_send(2).unwrap();
+            assert!(poll!(throttled.next()).is_pending());
+            window_sender.unbounded_send(()).unwrap();
+            assert_eq!(throttled.next().await, Some(2));
+            window_sender.unbounded_send(()).unwrap();
+            assert!(poll!(throttled.next()).is_pending());
+            sender.unbounded_send(3).unwrap();
+            sender.unbounded_send(4).unwrap();
+            window_sender.unbounded_send(()).unwrap();
+            assert_eq!(throttled.next().await, Some(4));
+            sender.unbounded_send(5).unwrap();
+            drop(sender);
+            assert_eq!(throttled.next().await, Some(5));
+            assert_eq!(throttled.next().await, None);
+        });
+    }
+
+    #[test]
+    fn always_ready_stream_is_throttled() {
+        let window = yield_on_none([None, Some(()), None, Some(())]);
+        let actual = block_on(
+            repeat(1)
+                .throttle_latest(window)
+                .take(2)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [1, 1]);
+    }
+
+    #[test]
+    fn always_ready_window_does_not_block_without_items() {
+        let mut throttled = pending::<i32>().throttle_latest(repeat(()));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(throttled.poll_next_unpin(&mut ctx), Poll::Pending);
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{
+    fmt::{self, Display},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Error yielded by [`TimeoutVia`] when its source stream is too slow
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Elapsed;
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("No item received before the timeout elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
+
+/// What a [`TimeoutVia`] stream does after yielding [`Elapsed`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OnElapsed {
+    /// Keeps yielding the items of the source stream
+    Continue,
+    /// Ends the stream
+    End,
+}
+
+/// Stream returned by [`StreamTools::timeout_via`](crate::StreamTools::timeout_via).
+#[pin_project]
+#[derive(Debug)]
+pub struct TimeoutVia<S, Tk> {
+    #[pin]
+    stream: Fuse<S>,
+    #[pin]
+    ticks: Fuse<Tk>,
+    elapsed_ticks: usize,
+    on_elapsed: OnElapsed,
+    done: bool,
+}
+
+impl<S, Tk> TimeoutVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    pub(crate) fn new(stream: S, ticks: Tk, on_elapsed: OnElapsed) -> Self {
+        Self {
+            stream: stream.fuse(),
+            ticks: ticks.fuse(),
+            elapsed_ticks: 0,
+            on_elapsed,
+            done: false,
+        }
+    }
+}
+
+impl<S, Tk> Stream for TimeoutVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        match this.stream.poll_next(ctx) {
+            Poll::Ready(Some(x)) => {
+                *this.elapsed_ticks = 0;
+                return Poll::Ready(Some(Ok(x)));
+            }
+            Poll::Ready(None) => {
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+        while let Poll::Ready(Some(_)) = this.ticks.as_mut().poll_next(ctx) {
+            *this.elapsed_ticks += 1;
+            if *this.elapsed_ticks > 1 {
+                *this.elapsed_ticks = 0;
+                *this.done = *this.on_elapsed == OnElapsed::End;
+                return Poll::Ready(Some(Err(Elapsed)));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<S, Tk> FusedStream for TimeoutVia<S, Tk>
+where
+    S: Stream,
+    Tk: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stream::{Elapsed, OnElapsed},
+        StreamTools,
+    };
+    use futures::{channel::mpsc, executor::block_on, poll, StreamExt};
+
+    #[test]
+    fn slow_source_triggers_elapsed_then_recovers() {
+        let (sender, receiver) = mpsc::unbounded();
+        let (tick_sender, ticks) = mpsc::unbounded();
+        let mut timed = receiver.timeout_via(ticks, OnElapsed::Continue);
+        block_on(async {
+            sender.unbounded_send(0).unwrap();
+            assert_eq!(timed.next().await, Some(Ok(0)));
+            tick_sender.unbounded_send(()).unwrap();
+            assert!(poll!(timed.next()).is_pending());
+            sender.unbounded_send(1).unwrap();
+            assert_eq!(timed.next().await, Some(Ok(1)));
+            tick_sender.unbounded_send(()).unwrap();
+            tick_sender.unbounded_send(()).unwrap();
+            assert_eq!(timed.next().await, Some(Err(Elapsed)));
+            sender.unbounded_send(2).unwrap();
+            drop(sender);
+            assert_eq!(timed.next().await, Some(Ok(2)));
+            assert_eq!(timed.next().await, None);
+        });
+    }
+
+    #[test]
+    fn stream_can_end_after_elapsed() {
+        let (_sender, receiver) = mpsc::unbounded::<()>();
+        let ticks = futures::stream::repeat(());
+        let actual = block_on(
+            receiver
+                .timeout_via(ticks, OnElapsed::End)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [Err(Elapsed)]);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatestWith;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`StreamTools::try_zip_latest_with`](crate::StreamTools::try_zip_latest_with).
+#[pin_project]
+pub struct TryZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    inner: ZipLatestWith<A, B, F>,
+    failed: bool,
+}
+
+impl<A, B, F, T, E> TryZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Result<T, E>,
+{
+    pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
+        Self {
+            inner: ZipLatestWith::new(stream, other_stream, combine),
+            failed: false,
+        }
+    }
+}
+
+impl<A, B, F> Debug for TryZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryZipLatestWith")
+            .field("failed", &self.failed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, B, F, T, E> Stream for TryZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Result<T, E>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.failed {
+            return Poll::Ready(None);
+        }
+        let res = ready!(this.inner.poll_next(ctx));
+        *this.failed = matches!(res, Some(Err(_)));
+        Poll::Ready(res)
+    }
+}
+
+impl<A, B, F, T, E> FusedStream for TryZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Result<T, E>,
+{
+    fn is_terminated(&self) -> bool {
+        self.failed || self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, stream::FusedStream, StreamExt};
+
+    #[test]
+    fn stream_ends_after_first_error() {
+        let a = yield_on_none([Some(1), None, Some(2), None, Some(3)]);
+        let b = yield_on_none([Some(10), None, None, None, None, None, Some(20)]);
+        let mut zipped =
+            Box::pin(a.try_zip_latest_with(
+                b,
+                |&a, &b| {
+                    if a == 2 {
+                        Err(a + b)
+                    } else {
+                        Ok(a + b)
+                    }
+                },
+            ));
+        block_on(async {
+            assert_eq!(zipped.next().await, Some(Ok(11)));
+            assert_eq!(zipped.next().await, Some(Err(12)));
+            assert!(zipped.is_terminated());
+            assert_eq!(zipped.next().await, None);
+        });
+    }
+}
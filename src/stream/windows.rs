@@ -0,0 +1,99 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::windows`](crate::StreamTools::windows).
+#[pin_project]
+#[derive(Debug)]
+pub struct Windows<S>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: Fuse<S>,
+    window: VecDeque<S::Item>,
+    size: usize,
+}
+
+impl<S> Windows<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub(crate) fn new(stream: S, size: usize) -> Self {
+        assert!(size > 0, "window size must be positive");
+        Self {
+            stream: stream.fuse(),
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<S> Stream for Windows<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while let Poll::Ready(x) = this.stream.as_mut().poll_next(ctx) {
+            let Some(x) = x else {
+                return Poll::Ready(None);
+            };
+            if this.window.len() == *this.size {
+                this.window.pop_front();
+            }
+            this.window.push_back(x);
+            if this.window.len() == *this.size {
+                return Poll::Ready(Some(this.window.iter().cloned().collect()));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<S> FusedStream for Windows<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamTools;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn windows_overlap() {
+        let actual = block_on(stream::iter(0..5).windows(3).collect::<Vec<_>>());
+        assert_eq!(actual, [[0, 1, 2], [1, 2, 3], [2, 3, 4]]);
+    }
+
+    #[test]
+    fn nothing_is_yielded_if_there_are_not_enough_items() {
+        let actual = block_on(stream::iter(0..2).windows(3).collect::<Vec<_>>());
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_of_size_0_panic() {
+        let _ = stream::iter(0..5).windows(0);
+    }
+}
@@ -1,16 +1,17 @@
 // Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
 
 use crate::stream::ZipLatestWith;
-use futures::{stream::FusedStream, Stream};
-use pin_project::pin_project;
-use std::{
+use core::{
     fmt::{self, Debug},
     pin::Pin,
     task::{Context, Poll},
 };
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
 
 /// Stream returned by [`StreamTools::zip_latest`](crate::StreamTools::zip_latest).
 #[pin_project]
+#[allow(clippy::type_complexity)]
 pub struct ZipLatest<A, B>(
     #[pin] ZipLatestWith<A, B, fn(&A::Item, &B::Item) -> (A::Item, B::Item)>,
 )
@@ -32,6 +33,41 @@ where
     }
 }
 
+impl<A, B> ZipLatest<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    /// Returns the latest items received from both streams
+    ///
+    /// Returns `None` if either stream has not produced an item yet. This does not poll the
+    /// streams.
+    pub fn latest(&self) -> Option<(&A::Item, &B::Item)> {
+        self.0.latest()
+    }
+
+    /// Returns the underlying streams
+    ///
+    /// The latest items received from the streams are dropped, including items that have not been
+    /// yielded yet.
+    pub fn into_inner(self) -> (A, B) {
+        let (a, b, _) = self.0.into_inner();
+        (a, b)
+    }
+}
+
+impl<A, B> Clone for ZipLatest<A, B>
+where
+    A: Stream + Clone,
+    A::Item: Clone,
+    B: Stream + Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<A, B> Debug for ZipLatest<A, B>
 where
     A: Stream,
@@ -73,7 +109,7 @@ mod tests {
     use crate::{stream::test_util::yield_on_none, StreamTools};
     use futures::{
         executor::block_on,
-        stream::{empty, repeat},
+        stream::{empty, iter, repeat},
         StreamExt,
     };
 
@@ -97,4 +133,60 @@ mod tests {
         let r = block_on(empty::<()>().zip_latest(repeat(())).collect::<Vec<_>>());
         assert_eq!(r, []);
     }
+
+    #[test]
+    fn last_item_of_ended_stream_is_paired_with_infinite_stream() {
+        let r = block_on(
+            iter([0, 1])
+                .zip_latest(iter(10..))
+                .take(4)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(r, [(0, 10), (1, 11), (1, 12), (1, 13)]);
+        let r = block_on(
+            iter(10..)
+                .zip_latest(iter([0, 1]))
+                .take(4)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(r, [(10, 0), (11, 1), (12, 1), (13, 1)]);
+    }
+
+    #[test]
+    fn zipped_stream_ends_once_both_streams_end() {
+        let a = yield_on_none([Some(0), Some(1)]);
+        let b = yield_on_none([Some(10), None, None, Some(11), Some(12)]);
+        let r = block_on(a.zip_latest(b).collect::<Vec<_>>());
+        assert_eq!(r, [(0, 10), (1, 10), (1, 11), (1, 12)]);
+    }
+
+    #[test]
+    fn clones_produce_identical_items() {
+        let mut zipped = repeat(1).zip_latest(iter(0..));
+        let first = block_on(zipped.next());
+        let clone = zipped.clone();
+        let a = block_on(zipped.take(5).collect::<Vec<_>>());
+        let b = block_on(clone.take(5).collect::<Vec<_>>());
+        assert_eq!(first, Some((1, 0)));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn latest_items_can_be_inspected() {
+        let mut zipped = iter([0, 1]).zip_latest(iter([10]));
+        assert_eq!(zipped.latest(), None);
+        assert_eq!(block_on(zipped.next()), Some((0, 10)));
+        assert_eq!(zipped.latest(), Some((&0, &10)));
+        assert_eq!(block_on(zipped.next()), Some((1, 10)));
+        assert_eq!(zipped.latest(), Some((&1, &10)));
+    }
+
+    #[test]
+    fn streams_can_be_recovered() {
+        let mut zipped = iter(0..3).zip_latest(iter(10..13));
+        assert_eq!(block_on(zipped.next()), Some((0, 10)));
+        let (a, b) = zipped.into_inner();
+        assert_eq!(block_on(a.collect::<Vec<_>>()), [1, 2]);
+        assert_eq!(block_on(b.collect::<Vec<_>>()), [11, 12]);
+    }
 }
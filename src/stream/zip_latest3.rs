@@ -0,0 +1,120 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatestWith3;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+type CloneAll3<A, B, C> = fn(&A, &B, &C) -> (A, B, C);
+
+type Inner<A, B, C> = ZipLatestWith3<
+    A,
+    B,
+    C,
+    CloneAll3<<A as Stream>::Item, <B as Stream>::Item, <C as Stream>::Item>,
+>;
+
+/// Stream returned by [`StreamTools::zip_latest3`](crate::StreamTools::zip_latest3).
+#[pin_project]
+pub struct ZipLatest3<A, B, C>(#[pin] Inner<A, B, C>)
+where
+    A: Stream,
+    B: Stream,
+    C: Stream;
+
+impl<A, B, C> ZipLatest3<A, B, C>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    C: Stream,
+    C::Item: Clone,
+{
+    pub(crate) fn new(a: A, b: B, c: C) -> Self {
+        Self(ZipLatestWith3::new(a, b, c, |a, b, c| {
+            (a.clone(), b.clone(), c.clone())
+        }))
+    }
+}
+
+impl<A, B, C> Debug for ZipLatest3<A, B, C>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatest3")
+    }
+}
+
+impl<A, B, C> Stream for ZipLatest3<A, B, C>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    C: Stream,
+    C::Item: Clone,
+{
+    type Item = (A::Item, B::Item, C::Item);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().0.poll_next(ctx)
+    }
+}
+
+impl<A, B, C> FusedStream for ZipLatest3<A, B, C>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    C: Stream,
+    C::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{empty, iter, repeat},
+        StreamExt,
+    };
+
+    #[test]
+    fn it_works() {
+        let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]);
+        let b = yield_on_none([None, Some('a'), Some('b'), None, None, None, Some('c')]);
+        let c = yield_on_none([Some("x"), None, None, None, None, None, None, Some("y")]);
+        let expected = [
+            (0, 'a', "x"),
+            (0, 'b', "x"),
+            (1, 'b', "x"),
+            (2, 'c', "x"),
+            (2, 'c', "y"),
+        ];
+        let actual = block_on(a.zip_latest3(b, c).collect::<Vec<_>>());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn zipping_latest_with_empty_stream_gives_empty_stream() {
+        let r = block_on(
+            repeat(0)
+                .zip_latest3(iter(['a']), empty::<()>())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(r, []);
+    }
+}
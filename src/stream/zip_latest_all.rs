@@ -1,13 +1,20 @@
 // Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
 
-use crate::stream::ZipLatestWithAll;
-use futures::{stream::FusedStream, Stream};
-use std::{
+use crate::stream::{ZipEvent, ZipLatestWithAll};
+use alloc::vec::{self, Vec};
+use core::{
+    array,
     pin::Pin,
     task::{Context, Poll},
 };
+use either::{Either, Left, Right};
+use futures::{stream::FusedStream, Stream};
+
+type CloneArray<T, const N: usize> = fn(&[T]) -> [T; N];
+type Ignore<T> = fn(&[T]);
 
 /// Stream returned by [`zip_latest_all`](crate::stream::zip_latest_all).
+#[allow(clippy::type_complexity)]
 pub struct ZipLatestAll<S>(ZipLatestWithAll<S, fn(&[S::Item]) -> Vec<S::Item>>)
 where
     S: Stream + Unpin;
@@ -47,9 +54,169 @@ where
     }
 }
 
+/// Stream returned by [`zip_latest_all_indexed`](crate::stream::zip_latest_all_indexed).
+#[allow(clippy::type_complexity)]
+pub struct ZipLatestAllIndexed<S>(ZipLatestWithAll<S, fn(&[S::Item]) -> Vec<S::Item>>)
+where
+    S: Stream + Unpin;
+
+impl<S> ZipLatestAllIndexed<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    pub(crate) fn new<I>(streams: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self(ZipLatestWithAll::new(streams, |items| items.to_vec()))
+    }
+}
+
+impl<S> Stream for ZipLatestAllIndexed<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    type Item = (Vec<usize>, Vec<S::Item>);
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let (res, event) = Pin::new(&mut self.0).poll_event(ctx);
+        res.map(|items| {
+            items.map(|items| match event {
+                ZipEvent::Emitted { changed } => (changed, items),
+                _ => unreachable!("an item is emitted along with the changed indices"),
+            })
+        })
+    }
+}
+
+impl<S> FusedStream for ZipLatestAllIndexed<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+/// Stream returned by [`zip_latest_all_array`](crate::stream::zip_latest_all_array).
+pub struct ZipLatestAllArray<S, const N: usize>(ZipLatestWithAll<S, CloneArray<S::Item, N>>)
+where
+    S: Stream + Unpin;
+
+impl<S, const N: usize> ZipLatestAllArray<S, N>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    pub(crate) fn new(streams: [S; N]) -> Self {
+        Self(ZipLatestWithAll::new(streams, |items| {
+            array::from_fn(|i| items[i].clone())
+        }))
+    }
+}
+
+impl<S, const N: usize> Stream for ZipLatestAllArray<S, N>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    type Item = [S::Item; N];
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.0).poll_next(ctx)
+    }
+}
+
+impl<S, const N: usize> FusedStream for ZipLatestAllArray<S, N>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+/// Stream returned by [`first_then_deltas`](crate::stream::first_then_deltas).
+pub struct FirstThenDeltas<S>
+where
+    S: Stream + Unpin,
+{
+    zipped: ZipLatestWithAll<S, Ignore<S::Item>>,
+    deltas: vec::IntoIter<usize>,
+    started: bool,
+}
+
+impl<S> FirstThenDeltas<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    pub(crate) fn new<I>(streams: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self {
+            zipped: ZipLatestWithAll::new(streams, |_| {}),
+            deltas: Vec::new().into_iter(),
+            started: false,
+        }
+    }
+
+    fn delta(&self, i: usize) -> Either<Vec<S::Item>, (usize, S::Item)> {
+        Right((i, self.zipped.items()[i].clone()))
+    }
+}
+
+impl<S> Stream for FirstThenDeltas<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    type Item = Either<Vec<S::Item>, (usize, S::Item)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(i) = self.deltas.next() {
+            return Poll::Ready(Some(self.delta(i)));
+        }
+        let (res, event) = Pin::new(&mut self.zipped).poll_event(ctx);
+        res.map(|res| {
+            res.map(|()| match event {
+                ZipEvent::Emitted { .. } if !self.started => {
+                    self.started = true;
+                    Left(self.zipped.items().to_vec())
+                }
+                ZipEvent::Emitted { changed } => {
+                    self.deltas = changed.into_iter();
+                    let i = self.deltas.next().expect("at least one stream changed");
+                    self.delta(i)
+                }
+                _ => unreachable!("an item is emitted along with the changed indices"),
+            })
+        })
+    }
+}
+
+impl<S> FusedStream for FirstThenDeltas<S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.deltas.len() == 0 && self.zipped.is_terminated()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::stream::{test_util::yield_on_none, zip_latest_all};
+    use crate::stream::{
+        first_then_deltas, test_util::yield_on_none, zip_latest_all, zip_latest_all_array,
+        zip_latest_all_indexed,
+    };
+    use either::Left;
     use futures::{
         executor::block_on,
         pin_mut,
@@ -75,6 +242,30 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn full_snapshot_is_followed_by_deltas() {
+        let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]);
+        pin_mut!(a);
+        let b = yield_on_none([None, Some(10), Some(11), Some(12), None, None, Some(13)]);
+        pin_mut!(b);
+        let mut actual =
+            block_on(first_then_deltas([a.left_stream(), b.right_stream()]).collect::<Vec<_>>());
+        assert_eq!(actual.remove(0), Left(vec![0, 10]));
+        let deltas = actual
+            .into_iter()
+            .map(|item| item.right().unwrap())
+            .collect::<Vec<_>>();
+        let deltas_of = |i| {
+            deltas
+                .iter()
+                .filter(|&&(j, _)| j == i)
+                .map(|&(_, n)| n)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(deltas_of(0), [1, 2]);
+        assert_eq!(deltas_of(1), [11, 12, 13]);
+    }
+
     #[test]
     fn zipping_latest_of_2_empty_streams_gives_empty_stream() {
         let r = block_on(zip_latest_all([empty::<()>(), empty()]).collect::<Vec<_>>());
@@ -89,4 +280,39 @@ mod tests {
         );
         assert_eq!(r, <[Vec<()>; 0]>::default());
     }
+
+    #[test]
+    fn indices_of_streams_that_advanced_are_reported() {
+        let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]);
+        pin_mut!(a);
+        let b = yield_on_none([None, Some(10), Some(11), Some(12), None, None, Some(13)]);
+        pin_mut!(b);
+        let expected = [
+            (vec![0, 1], vec![0, 10]),
+            (vec![1, 0], vec![1, 11]),
+            (vec![1], vec![1, 12]),
+            (vec![0], vec![2, 12]),
+            (vec![1], vec![2, 13]),
+        ];
+        let actual = block_on(
+            zip_latest_all_indexed([a.left_stream(), b.right_stream()]).collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn array_output_matches_vec_output() {
+        let items = || {
+            let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]);
+            let b = yield_on_none([None, Some(10), Some(11), Some(12), None, None, Some(13)]);
+            [Box::pin(a).left_stream(), Box::pin(b).right_stream()]
+        };
+        let expected = block_on(zip_latest_all(items()).collect::<Vec<_>>());
+        let actual = block_on(
+            zip_latest_all_array(items())
+                .map(Vec::from)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
 }
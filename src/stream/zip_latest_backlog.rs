@@ -0,0 +1,152 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::zip_latest_backlog`](crate::StreamTools::zip_latest_backlog).
+#[pin_project]
+#[derive(Debug)]
+pub struct ZipLatestBacklog<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    backlog: VecDeque<A::Item>,
+    other_backlog: VecDeque<B::Item>,
+    latest: Option<A::Item>,
+    other_latest: Option<B::Item>,
+    capacity: usize,
+    combine: F,
+}
+
+impl<A, B, F, T> ZipLatestBacklog<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    pub(crate) fn new(stream: A, other_stream: B, capacity: usize, combine: F) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            stream: stream.fuse(),
+            other_stream: other_stream.fuse(),
+            backlog: VecDeque::with_capacity(capacity),
+            other_backlog: VecDeque::with_capacity(capacity),
+            latest: None,
+            other_latest: None,
+            capacity,
+            combine,
+        }
+    }
+}
+
+impl<A, B, F, T> Stream for ZipLatestBacklog<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while this.backlog.len() < *this.capacity {
+            match this.stream.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(x)) => this.backlog.push_back(x),
+                _ => break,
+            }
+        }
+        while this.other_backlog.len() < *this.capacity {
+            match this.other_stream.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(x)) => this.other_backlog.push_back(x),
+                _ => break,
+            }
+        }
+        let has_new = !this.backlog.is_empty() || !this.other_backlog.is_empty();
+        let has_value = !this.backlog.is_empty() || this.latest.is_some();
+        let other_has_value = !this.other_backlog.is_empty() || this.other_latest.is_some();
+        if has_new && has_value && other_has_value {
+            if let Some(x) = this.backlog.pop_front() {
+                *this.latest = Some(x);
+            }
+            if let Some(x) = this.other_backlog.pop_front() {
+                *this.other_latest = Some(x);
+            }
+            if let (Some(a), Some(b)) = (&*this.latest, &*this.other_latest) {
+                return Poll::Ready(Some((this.combine)(a, b)));
+            }
+        }
+        if (!has_value && this.stream.is_done())
+            || (!other_has_value && this.other_stream.is_done())
+            || (!has_new && this.stream.is_done() && this.other_stream.is_done())
+        {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<A, B, F, T> FusedStream for ZipLatestBacklog<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        let has_value = !self.backlog.is_empty() || self.latest.is_some();
+        let other_has_value = !self.other_backlog.is_empty() || self.other_latest.is_some();
+        let has_new = !self.backlog.is_empty() || !self.other_backlog.is_empty();
+        (!has_value && self.stream.is_done())
+            || (!other_has_value && self.other_stream.is_done())
+            || (!has_new && self.stream.is_done() && self.other_stream.is_done())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{empty, iter},
+        StreamExt,
+    };
+
+    #[test]
+    fn burst_is_not_collapsed() {
+        let a = iter([1, 2, 3]);
+        let b = yield_on_none([None, Some(10)]);
+        let actual = block_on(a.zip_latest_backlog(b, 4, |i, j| i + j).collect::<Vec<_>>());
+        assert_eq!(actual, [11, 12, 13]);
+    }
+
+    #[test]
+    fn burst_beyond_backlog_is_delayed() {
+        let a = iter([1, 2, 3]);
+        let b = yield_on_none([None, Some(10), Some(20)]);
+        let actual = block_on(a.zip_latest_backlog(b, 1, |i, j| i + j).collect::<Vec<_>>());
+        assert_eq!(actual, [11, 22, 23]);
+    }
+
+    #[test]
+    fn zipping_with_empty_stream_gives_empty_stream() {
+        let r = block_on(
+            iter([1])
+                .zip_latest_backlog(empty::<i32>(), 2, |i, j| i + j)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(r, []);
+    }
+}
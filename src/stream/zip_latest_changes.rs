@@ -0,0 +1,107 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatestWith;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+type CloneBoth<A, B> = fn(&A, &B) -> (A, B);
+
+/// Streams that produced the items of a [`ZipLatestChanges`] item
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Changed {
+    /// Only the first stream produced a new item
+    Left,
+    /// Only the second stream produced a new item
+    Right,
+    /// Both streams produced a new item
+    Both,
+}
+
+/// Stream returned by [`StreamTools::zip_latest_changes`](crate::StreamTools::zip_latest_changes).
+#[pin_project]
+pub struct ZipLatestChanges<A, B>(#[pin] ZipLatestWith<A, B, CloneBoth<A::Item, B::Item>>)
+where
+    A: Stream,
+    B: Stream;
+
+impl<A, B> ZipLatestChanges<A, B>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+{
+    pub(crate) fn new(stream: A, other_stream: B) -> Self {
+        Self(ZipLatestWith::new(stream, other_stream, |a, b| {
+            (a.clone(), b.clone())
+        }))
+    }
+}
+
+impl<A, B> Debug for ZipLatestChanges<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestChanges")
+    }
+}
+
+impl<A, B> Stream for ZipLatestChanges<A, B>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+{
+    type Item = (A::Item, B::Item, Changed);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project()
+            .0
+            .poll_changes(ctx)
+            .map(|res| res.map(|((a, b), changed)| (a, b, changed)))
+    }
+}
+
+impl<A, B> FusedStream for ZipLatestChanges<A, B>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stream::{test_util::yield_on_none, Changed},
+        StreamTools,
+    };
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn changed_sides_are_reported() {
+        let a = yield_on_none([Some(0), None, Some(1), None, Some(2)]);
+        let b = yield_on_none([Some(10), Some(11), None, Some(12), Some(13)]);
+        let expected = [
+            (0, 10, Changed::Both),
+            (0, 11, Changed::Right),
+            (1, 11, Changed::Left),
+            (1, 12, Changed::Right),
+            (2, 13, Changed::Both),
+        ];
+        let actual = block_on(a.zip_latest_changes(b).collect::<Vec<_>>());
+        assert_eq!(actual, expected);
+    }
+}
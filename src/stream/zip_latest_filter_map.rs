@@ -0,0 +1,91 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatestWith;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`StreamTools::zip_latest_filter_map`](crate::StreamTools::zip_latest_filter_map).
+#[pin_project]
+pub struct ZipLatestFilterMap<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    inner: ZipLatestWith<A, B, F>,
+}
+
+impl<A, B, F, T> ZipLatestFilterMap<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Option<T>,
+{
+    pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
+        Self {
+            inner: ZipLatestWith::new(stream, other_stream, combine),
+        }
+    }
+}
+
+impl<A, B, F> Debug for ZipLatestFilterMap<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestFilterMap")
+    }
+}
+
+impl<A, B, F, T> Stream for ZipLatestFilterMap<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.inner.as_mut().poll_next(ctx)) {
+                Some(Some(x)) => return Poll::Ready(Some(x)),
+                Some(None) => {}
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<A, B, F, T> FusedStream for ZipLatestFilterMap<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> Option<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn none_results_are_skipped() {
+        let a = yield_on_none([Some(1), None, Some(2), None, Some(3), Some(1), Some(4)]);
+        let b = yield_on_none([Some(2), None, None, None, Some(1)]);
+        let diffs = a.zip_latest_filter_map(b, |&a, &b| (a != b).then(|| a - b));
+        let actual = block_on(Box::pin(diffs).collect::<Vec<_>>());
+        assert_eq!(actual, [-1, 2, 3]);
+    }
+}
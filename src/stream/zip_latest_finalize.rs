@@ -0,0 +1,159 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::zip_latest_with::StreamState;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`StreamTools::zip_latest_with_finalize`](crate::StreamTools::zip_latest_with_finalize).
+#[pin_project]
+#[derive(Debug)]
+pub struct ZipLatestFinalize<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    state: StreamState<A::Item>,
+    other_state: StreamState<B::Item>,
+    finalized: bool,
+    other_finalized: bool,
+    combine: F,
+}
+
+impl<A, B, F, T> ZipLatestFinalize<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            other_stream: other_stream.fuse(),
+            state: StreamState::Nothing,
+            other_state: StreamState::Nothing,
+            finalized: false,
+            other_finalized: false,
+            combine,
+        }
+    }
+}
+
+impl<A, B, F, T> Stream for ZipLatestFinalize<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    type Item = (T, Option<Side>);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if this.state.needs_poll() {
+            if let Poll::Ready(Some(x)) = this.stream.as_mut().poll_next(ctx) {
+                *this.state = StreamState::New(x);
+            }
+        }
+        if this.other_state.needs_poll() {
+            if let Poll::Ready(Some(x)) = this.other_stream.as_mut().poll_next(ctx) {
+                *this.other_state = StreamState::New(x);
+            }
+        }
+        if let (Some(a), Some(b)) = (this.state.get(), this.other_state.get()) {
+            let side = if this.stream.is_done() && !*this.finalized {
+                *this.finalized = true;
+                Some(Side::Left)
+            } else if this.other_stream.is_done() && !*this.other_finalized {
+                *this.other_finalized = true;
+                Some(Side::Right)
+            } else {
+                None
+            };
+            if side.is_some() || this.state.is_new() || this.other_state.is_new() {
+                let res = (this.combine)(a, b);
+                this.state.mark_yielded();
+                this.other_state.mark_yielded();
+                return Poll::Ready(Some((res, side)));
+            }
+        }
+        let ended = (this.state.get().is_none() && this.stream.is_done())
+            || (this.other_state.get().is_none() && this.other_stream.is_done())
+            || (this.stream.is_done() && this.other_stream.is_done());
+        if ended {
+            *this.state = StreamState::Nothing;
+            *this.other_state = StreamState::Nothing;
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<A, B, F, T> FusedStream for ZipLatestFinalize<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        (self.state.get().is_none() && self.stream.is_done())
+            || (self.other_state.get().is_none() && self.other_stream.is_done())
+    }
+}
+
+/// Identifies one of the two streams zipped by
+/// [`StreamTools::zip_latest_with_finalize`](crate::StreamTools::zip_latest_with_finalize)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Side {
+    /// The stream `zip_latest_with_finalize` was called on
+    Left,
+    /// The stream passed as argument to `zip_latest_with_finalize`
+    Right,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stream::{test_util::yield_on_none, Side},
+        StreamTools,
+    };
+    use futures::{executor::block_on, stream::iter, StreamExt};
+
+    #[test]
+    fn final_value_is_tagged_when_a_side_ends() {
+        let a = iter([0]);
+        let b = yield_on_none([Some(10), None, Some(11)]);
+        let expected = [
+            (10, None),
+            (10, Some(Side::Left)),
+            (11, None),
+            (11, Some(Side::Right)),
+        ];
+        let actual = block_on(
+            a.zip_latest_with_finalize(b, |i, j| i + j)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn side_ending_without_items_ends_stream() {
+        let r = block_on(
+            iter([0])
+                .zip_latest_with_finalize(iter(Vec::<i32>::new()), |i, j| i + j)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(r, []);
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatestAll;
+use core::{
+    fmt::{self, Debug},
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use std::collections::HashMap;
+
+/// Stream returned by [`zip_latest_labeled`](crate::stream::zip_latest_labeled).
+pub struct ZipLatestLabeled<K, S>
+where
+    S: Stream + Unpin,
+{
+    inner: ZipLatestAll<S>,
+    labels: Vec<K>,
+}
+
+impl<K, S> ZipLatestLabeled<K, S>
+where
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    pub(crate) fn new<I>(streams: I) -> Self
+    where
+        I: IntoIterator<Item = (K, S)>,
+    {
+        let (labels, streams): (Vec<_>, Vec<_>) = streams.into_iter().unzip();
+        Self {
+            inner: ZipLatestAll::new(streams),
+            labels,
+        }
+    }
+}
+
+impl<K, S> Debug for ZipLatestLabeled<K, S>
+where
+    K: Debug,
+    S: Stream + Unpin,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZipLatestLabeled")
+            .field("labels", &self.labels)
+            .finish()
+    }
+}
+
+impl<K, S> Unpin for ZipLatestLabeled<K, S> where S: Stream + Unpin {}
+
+impl<K, S> Stream for ZipLatestLabeled<K, S>
+where
+    K: Clone + Eq + Hash,
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    type Item = HashMap<K, S::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        Pin::new(&mut this.inner)
+            .poll_next(ctx)
+            .map(|items| items.map(|items| this.labels.iter().cloned().zip(items).collect()))
+    }
+}
+
+impl<K, S> FusedStream for ZipLatestLabeled<K, S>
+where
+    K: Clone + Eq + Hash,
+    S: Stream + Unpin,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::{test_util::yield_on_none, zip_latest_labeled};
+    use futures::{executor::block_on, StreamExt};
+    use std::collections::HashMap;
+
+    #[test]
+    fn latest_items_are_keyed_by_label() {
+        let host = yield_on_none([Some("a"), None, None, None, None, Some("b")]);
+        let port = yield_on_none([Some("80"), None, Some("81")]);
+        let user = yield_on_none([Some("x")]);
+        let streams = [
+            ("host", Box::pin(host).left_stream().left_stream()),
+            ("port", Box::pin(port).right_stream().left_stream()),
+            ("user", Box::pin(user).right_stream()),
+        ];
+        let actual = block_on(zip_latest_labeled(streams).collect::<Vec<_>>());
+        let expected = [
+            HashMap::from([("host", "a"), ("port", "80"), ("user", "x")]),
+            HashMap::from([("host", "a"), ("port", "81"), ("user", "x")]),
+            HashMap::from([("host", "b"), ("port", "81"), ("user", "x")]),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatest;
+use alloc::rc::Rc;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{FusedStream, Map},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+type Shared<S> = Map<S, fn(<S as Stream>::Item) -> Rc<<S as Stream>::Item>>;
+
+/// Stream returned by [`StreamTools::zip_latest_shared`](crate::StreamTools::zip_latest_shared).
+#[pin_project]
+pub struct ZipLatestShared<A, B>(#[pin] ZipLatest<Shared<A>, Shared<B>>)
+where
+    A: Stream,
+    B: Stream;
+
+impl<A, B> ZipLatestShared<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    pub(crate) fn new(stream: A, other_stream: B) -> Self {
+        Self(ZipLatest::new(
+            stream.map(Rc::new as fn(_) -> _),
+            other_stream.map(Rc::new as fn(_) -> _),
+        ))
+    }
+
+    /// Returns the latest items received from both streams
+    ///
+    /// Returns `None` if either stream has not produced an item yet. This does not poll the
+    /// streams.
+    pub fn latest(&self) -> Option<(&A::Item, &B::Item)> {
+        self.0.latest().map(|(a, b)| (&**a, &**b))
+    }
+}
+
+impl<A, B> Debug for ZipLatestShared<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestShared")
+    }
+}
+
+impl<A, B> Stream for ZipLatestShared<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    type Item = (Rc<A::Item>, Rc<B::Item>);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().0.poll_next(ctx)
+    }
+}
+
+impl<A, B> FusedStream for ZipLatestShared<A, B>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, StreamExt};
+    use std::{cell::Cell, rc::Rc};
+
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Expensive(i32);
+
+    impl Clone for Expensive {
+        fn clone(&self) -> Self {
+            CLONES.with(|clones| clones.set(clones.get() + 1));
+            Expensive(self.0)
+        }
+    }
+
+    #[test]
+    fn latest_items_are_shared_without_cloning() {
+        let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]).map(Expensive);
+        let b = yield_on_none([None, Some(10), Some(11), Some(12), None, None, Some(13)])
+            .map(Expensive);
+        let actual = block_on(a.zip_latest_shared(b).collect::<Vec<_>>());
+        assert!(Rc::ptr_eq(&actual[0].0, &actual[1].0));
+        let actual = actual.iter().map(|(a, b)| (a.0, b.0)).collect::<Vec<_>>();
+        assert_eq!(actual, [(0, 10), (0, 11), (1, 12), (2, 13)]);
+        assert_eq!(CLONES.with(Cell::get), 0);
+    }
+}
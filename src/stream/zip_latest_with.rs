@@ -1,15 +1,13 @@
 // Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
 
-use futures::{
-    stream::{Fuse, FusedStream},
-    Stream, StreamExt,
-};
-use pin_project::pin_project;
-use std::{
+use crate::stream::{fuse::Fuse, Changed};
+use core::{
     mem,
     pin::Pin,
     task::{Context, Poll},
 };
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
 
 /// Stream returned by [`StreamTools::zip_latest_with`](crate::StreamTools::zip_latest_with).
 #[pin_project]
@@ -36,13 +34,72 @@ where
 {
     pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
         Self {
-            stream: stream.fuse(),
-            other_stream: other_stream.fuse(),
+            stream: Fuse::new(stream),
+            other_stream: Fuse::new(other_stream),
             state: StreamState::Nothing,
             other_state: StreamState::Nothing,
             combine,
         }
     }
+
+    pub(crate) fn with_seeds(
+        stream: A,
+        other_stream: B,
+        seed: A::Item,
+        other_seed: B::Item,
+        combine: F,
+    ) -> Self {
+        Self {
+            state: StreamState::Yielded(seed),
+            other_state: StreamState::Yielded(other_seed),
+            ..Self::new(stream, other_stream, combine)
+        }
+    }
+}
+
+impl<A, B, F> ZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    /// Returns the latest items received from both streams
+    ///
+    /// Returns `None` if either stream has not produced an item yet. This does not poll the
+    /// streams.
+    pub fn latest(&self) -> Option<(&A::Item, &B::Item)> {
+        self.state.get().zip(self.other_state.get())
+    }
+
+    /// Returns the underlying streams and combining function
+    ///
+    /// The latest items received from the streams are dropped, including items that have not been
+    /// combined yet.
+    pub fn into_inner(self) -> (A, B, F) {
+        (
+            self.stream.into_inner(),
+            self.other_stream.into_inner(),
+            self.combine,
+        )
+    }
+}
+
+impl<A, B, F> Clone for ZipLatestWith<A, B, F>
+where
+    A: Stream + Clone,
+    A::Item: Clone,
+    B: Stream + Clone,
+    B::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            stream: self.stream.clone(),
+            other_stream: self.other_stream.clone(),
+            state: self.state.clone(),
+            other_state: self.other_state.clone(),
+            combine: self.combine.clone(),
+        }
+    }
 }
 
 impl<A, B, F, T> Stream for ZipLatestWith<A, B, F>
@@ -54,6 +111,22 @@ where
     type Item = T;
 
     fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_changes(ctx)
+            .map(|res| res.map(|(combined, _)| combined))
+    }
+}
+
+impl<A, B, F, T> ZipLatestWith<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    /// Polls like `poll_next` and also reports which streams produced the items being combined
+    pub(super) fn poll_changes(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<(T, Changed)>> {
         let mut this = self.project();
         if this.state.needs_poll() {
             if let Poll::Ready(Some(x)) = this.stream.as_mut().poll_next(ctx) {
@@ -65,37 +138,13 @@ where
                 *this.other_state = StreamState::New(x);
             }
         }
-        let (res, new_state, new_other_state) = match (
-            mem::replace(this.state, StreamState::Nothing),
-            mem::replace(this.other_state, StreamState::Nothing),
-        ) {
-            (StreamState::New(a), StreamState::New(b))
-            | (StreamState::New(a), StreamState::Yielded(b))
-            | (StreamState::Yielded(a), StreamState::New(b)) => (
-                Poll::Ready(Some((this.combine)(&a, &b))),
-                StreamState::Yielded(a),
-                StreamState::Yielded(b),
-            ),
-            (StreamState::Nothing, _) if this.stream.is_done() => (
-                Poll::Ready(None),
-                StreamState::Nothing,
-                StreamState::Nothing,
-            ),
-            (_, StreamState::Nothing) if this.other_stream.is_done() => (
-                Poll::Ready(None),
-                StreamState::Nothing,
-                StreamState::Nothing,
-            ),
-            _ if this.stream.is_done() && this.other_stream.is_done() => (
-                Poll::Ready(None),
-                StreamState::Nothing,
-                StreamState::Nothing,
-            ),
-            (a, b) => (Poll::Pending, a, b),
-        };
-        *this.state = new_state;
-        *this.other_state = new_other_state;
-        res
+        combine_states(
+            this.state,
+            this.other_state,
+            this.stream.is_done(),
+            this.other_stream.is_done(),
+            this.combine,
+        )
     }
 }
 
@@ -106,36 +155,123 @@ where
     F: FnMut(&A::Item, &B::Item) -> T,
 {
     fn is_terminated(&self) -> bool {
-        matches!(
-            (&self.state, self.stream.is_done()),
-            (StreamState::Nothing, true)
-        ) || matches!(
-            (&self.other_state, self.other_stream.is_done()),
-            (StreamState::Nothing, true)
+        states_terminated(
+            &self.state,
+            &self.other_state,
+            self.stream.is_done(),
+            self.other_stream.is_done(),
         )
     }
 }
 
-#[derive(Debug)]
-enum StreamState<T> {
+/// Combines the latest items of two streams if either is new, or reports the end of the zip
+pub(super) fn combine_states<A, B, F, T>(
+    state: &mut StreamState<A>,
+    other_state: &mut StreamState<B>,
+    done: bool,
+    other_done: bool,
+    combine: &mut F,
+) -> Poll<Option<(T, Changed)>>
+where
+    F: FnMut(&A, &B) -> T,
+{
+    let (res, new_state, new_other_state) = match (
+        mem::replace(state, StreamState::Nothing),
+        mem::replace(other_state, StreamState::Nothing),
+    ) {
+        (StreamState::New(a), StreamState::New(b)) => (
+            Poll::Ready(Some((combine(&a, &b), Changed::Both))),
+            StreamState::Yielded(a),
+            StreamState::Yielded(b),
+        ),
+        (StreamState::New(a), StreamState::Yielded(b)) => (
+            Poll::Ready(Some((combine(&a, &b), Changed::Left))),
+            StreamState::Yielded(a),
+            StreamState::Yielded(b),
+        ),
+        (StreamState::Yielded(a), StreamState::New(b)) => (
+            Poll::Ready(Some((combine(&a, &b), Changed::Right))),
+            StreamState::Yielded(a),
+            StreamState::Yielded(b),
+        ),
+        (StreamState::Nothing, _) if done => (
+            Poll::Ready(None),
+            StreamState::Nothing,
+            StreamState::Nothing,
+        ),
+        (_, StreamState::Nothing) if other_done => (
+            Poll::Ready(None),
+            StreamState::Nothing,
+            StreamState::Nothing,
+        ),
+        _ if done && other_done => (
+            Poll::Ready(None),
+            StreamState::Nothing,
+            StreamState::Nothing,
+        ),
+        (a, b) => (Poll::Pending, a, b),
+    };
+    *state = new_state;
+    *other_state = new_other_state;
+    res
+}
+
+/// Returns whether a zip of two streams in the given states can no longer yield
+pub(super) fn states_terminated<A, B>(
+    state: &StreamState<A>,
+    other_state: &StreamState<B>,
+    done: bool,
+    other_done: bool,
+) -> bool {
+    matches!((state, done), (StreamState::Nothing, true))
+        || matches!((other_state, other_done), (StreamState::Nothing, true))
+        || (done && other_done && !state.is_new() && !other_state.is_new())
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum StreamState<T> {
     Nothing,
     New(T),
     Yielded(T),
 }
 
 impl<T> StreamState<T> {
-    fn needs_poll(&self) -> bool {
+    pub(super) fn needs_poll(&self) -> bool {
         match self {
             StreamState::Nothing | StreamState::Yielded(_) => true,
             StreamState::New(_) => false,
         }
     }
+
+    pub(super) fn is_new(&self) -> bool {
+        matches!(self, StreamState::New(_))
+    }
+
+    pub(super) fn get(&self) -> Option<&T> {
+        match self {
+            StreamState::Nothing => None,
+            StreamState::New(x) | StreamState::Yielded(x) => Some(x),
+        }
+    }
+
+    pub(super) fn mark_yielded(&mut self) {
+        *self = match mem::replace(self, StreamState::Nothing) {
+            StreamState::New(x) | StreamState::Yielded(x) => StreamState::Yielded(x),
+            StreamState::Nothing => StreamState::Nothing,
+        };
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{stream::test_util::yield_on_none, StreamTools};
-    use futures::{executor::block_on, StreamExt};
+    use futures::{
+        executor::block_on,
+        stream::{iter, FusedStream},
+        task::noop_waker_ref,
+        StreamExt,
+    };
+    use std::task::{Context, Poll};
 
     #[test]
     fn it_works() {
@@ -145,4 +281,58 @@ mod tests {
         let actual = block_on(a.zip_latest_with(b, |i, j| i + j).collect::<Vec<_>>());
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn latest_items_can_be_inspected() {
+        let a = yield_on_none([Some(0), None, Some(1)]);
+        let b = yield_on_none([None, Some(10)]);
+        let mut zipped = Box::pin(a.zip_latest_with(b, |i, j| i + j));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(zipped.latest(), None);
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Pending);
+        assert_eq!(zipped.latest(), None);
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(10)));
+        assert_eq!(zipped.latest(), Some((&0, &10)));
+    }
+
+    #[test]
+    fn seeds_are_combined_with_new_items() {
+        let a = yield_on_none([Some(0), None, Some(1)]);
+        let b = futures::stream::empty();
+        let expected = [100, 101];
+        let actual = block_on(
+            a.zip_latest_with_seeded(b, -1, 100, |i, j| i + j)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn is_not_terminated_while_held_values_can_still_be_combined() {
+        let a = iter([1]);
+        let b = yield_on_none([Some(10), None, Some(11)]);
+        let mut zipped = Box::pin(a.zip_latest_with(b, |i, j| i + j));
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(11)));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Pending);
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(12)));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(None));
+        assert!(zipped.is_terminated());
+    }
+
+    #[test]
+    fn is_terminated_once_both_streams_end_with_held_values() {
+        let mut zipped = iter([1, 2]).zip_latest_with(iter([10]), |i, j| i + j);
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(11)));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(12)));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(None));
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(None));
+    }
 }
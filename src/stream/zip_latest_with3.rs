@@ -0,0 +1,137 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::zip_latest_with::StreamState;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by [`StreamTools::zip_latest_with3`](crate::StreamTools::zip_latest_with3).
+#[pin_project]
+#[derive(Debug)]
+pub struct ZipLatestWith3<A, B, C, F>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+{
+    #[pin]
+    a: Fuse<A>,
+    #[pin]
+    b: Fuse<B>,
+    #[pin]
+    c: Fuse<C>,
+    a_state: StreamState<A::Item>,
+    b_state: StreamState<B::Item>,
+    c_state: StreamState<C::Item>,
+    combine: F,
+}
+
+impl<A, B, C, F, T> ZipLatestWith3<A, B, C, F>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+    F: FnMut(&A::Item, &B::Item, &C::Item) -> T,
+{
+    pub(crate) fn new(a: A, b: B, c: C, combine: F) -> Self {
+        Self {
+            a: a.fuse(),
+            b: b.fuse(),
+            c: c.fuse(),
+            a_state: StreamState::Nothing,
+            b_state: StreamState::Nothing,
+            c_state: StreamState::Nothing,
+            combine,
+        }
+    }
+}
+
+impl<A, B, C, F, T> Stream for ZipLatestWith3<A, B, C, F>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+    F: FnMut(&A::Item, &B::Item, &C::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if this.a_state.needs_poll() {
+            if let Poll::Ready(Some(x)) = this.a.as_mut().poll_next(ctx) {
+                *this.a_state = StreamState::New(x);
+            }
+        }
+        if this.b_state.needs_poll() {
+            if let Poll::Ready(Some(x)) = this.b.as_mut().poll_next(ctx) {
+                *this.b_state = StreamState::New(x);
+            }
+        }
+        if this.c_state.needs_poll() {
+            if let Poll::Ready(Some(x)) = this.c.as_mut().poll_next(ctx) {
+                *this.c_state = StreamState::New(x);
+            }
+        }
+        let any_new = this.a_state.is_new() || this.b_state.is_new() || this.c_state.is_new();
+        match (this.a_state.get(), this.b_state.get(), this.c_state.get()) {
+            (Some(a), Some(b), Some(c)) if any_new => {
+                let res = (this.combine)(a, b, c);
+                this.a_state.mark_yielded();
+                this.b_state.mark_yielded();
+                this.c_state.mark_yielded();
+                Poll::Ready(Some(res))
+            }
+            (a, b, c)
+                if (a.is_none() && this.a.is_done())
+                    || (b.is_none() && this.b.is_done())
+                    || (c.is_none() && this.c.is_done())
+                    || (this.a.is_done() && this.b.is_done() && this.c.is_done()) =>
+            {
+                *this.a_state = StreamState::Nothing;
+                *this.b_state = StreamState::Nothing;
+                *this.c_state = StreamState::Nothing;
+                Poll::Ready(None)
+            }
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<A, B, C, F, T> FusedStream for ZipLatestWith3<A, B, C, F>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+    F: FnMut(&A::Item, &B::Item, &C::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        (self.a_state.get().is_none() && self.a.is_done())
+            || (self.b_state.get().is_none() && self.b.is_done())
+            || (self.c_state.get().is_none() && self.c.is_done())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn it_works() {
+        let a = yield_on_none([Some(0), None, Some(1), None, None, Some(2)]);
+        let b = yield_on_none([None, Some(10), Some(11), Some(12), None, None, Some(13)]);
+        let c = yield_on_none([Some(100), None, None, None, None, None, None, Some(101)]);
+        let expected = [110, 111, 113, 115, 116];
+        let actual = block_on(
+            a.zip_latest_with3(b, c, |i, j, k| i + j + k)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+}
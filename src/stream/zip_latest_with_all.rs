@@ -1,26 +1,32 @@
 // Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
 
+use crate::stream::IndexWith;
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use futures::{
     future::{join_all, JoinAll},
     stream::{FusedStream, FuturesUnordered, StreamFuture},
     Stream, StreamExt,
 };
 use pin_project::pin_project;
-use std::{
-    fmt::{self, Debug},
-    future::Future,
-    pin::Pin,
-    task::{ready, Context, Poll},
-};
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex};
 
 /// Stream returned by [`zip_latest_with_all`](crate::stream::zip_latest_with_all).
 #[pin_project]
-pub struct ZipLatestWithAll<S, F>
+pub struct ZipLatestWithAll<S, F, O = fn(ZipEvent)>
 where
     S: Stream + Unpin,
 {
     inner: Inner<S>,
     combine: F,
+    observer: O,
+    lossy: bool,
 }
 
 impl<S, F, T> ZipLatestWithAll<S, F>
@@ -29,64 +35,156 @@ where
     F: FnMut(&[S::Item]) -> T,
 {
     pub(crate) fn new<I>(streams: I, combine: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self::with_observer(streams, combine, |_| {})
+    }
+
+    pub(crate) fn lossy<I>(streams: I, combine: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self {
+            lossy: true,
+            ..Self::new(streams, combine)
+        }
+    }
+}
+
+impl<S, F, O, T> ZipLatestWithAll<S, F, O>
+where
+    S: Stream + Unpin,
+    F: FnMut(&[S::Item]) -> T,
+    O: FnMut(ZipEvent),
+{
+    pub(crate) fn with_observer<I>(streams: I, combine: F, observer: O) -> Self
     where
         I: IntoIterator<Item = S>,
     {
         Self {
             inner: Inner::Fill(join_all(streams.into_iter().map(|s| s.into_future()))),
             combine,
+            observer,
+            lossy: false,
+        }
+    }
+
+    /// Replaces the function combining the latest items
+    ///
+    /// `combine` is used from the next emitted item on. The latest items of the underlying streams
+    /// are preserved.
+    pub fn set_combine(&mut self, combine: F) {
+        self.combine = combine;
+    }
+
+    /// Returns the latest items, or an empty slice if not all streams have produced an item yet
+    pub(super) fn items(&self) -> &[S::Item] {
+        match &self.inner {
+            Inner::Filled(Filled { items, .. }) => items,
+            Inner::Fill(_) => &[],
+        }
+    }
+
+    /// Combines the latest items again, if all streams have produced an item
+    pub(super) fn recombine(&mut self) -> Option<T> {
+        match &self.inner {
+            Inner::Filled(Filled { items, .. }) if !items.is_empty() => Some((self.combine)(items)),
+            _ => None,
         }
     }
 }
 
-impl<S, F> Debug for ZipLatestWithAll<S, F>
+impl<S, F, O> Debug for ZipLatestWithAll<S, F, O>
 where
     S: Stream + Unpin,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("ZipLatestWithAll")
+        let mut s = f.debug_struct("ZipLatestWithAll");
+        match &self.inner {
+            Inner::Fill(_) => s.field("phase", &format_args!("Fill")),
+            Inner::Filled(Filled { items, next_items }) => s
+                .field("phase", &format_args!("Filled"))
+                .field("items", &items.len())
+                .field("pending", &next_items.len()),
+        };
+        s.field("lossy", &self.lossy).finish_non_exhaustive()
     }
 }
 
-impl<S, F, T> Stream for ZipLatestWithAll<S, F>
+impl<S, F, O, T> Stream for ZipLatestWithAll<S, F, O>
 where
     S: Stream + Unpin,
     F: FnMut(&[S::Item]) -> T,
+    O: FnMut(ZipEvent),
 {
     type Item = T;
 
-    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let (res, event) = self.as_mut().poll_event(ctx);
+        (self.project().observer)(event);
+        res
+    }
+}
+
+impl<S, F, O, T> ZipLatestWithAll<S, F, O>
+where
+    S: Stream + Unpin,
+    F: FnMut(&[S::Item]) -> T,
+{
+    /// Polls the underlying streams and describes what happened
+    pub(super) fn poll_event(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> (Poll<Option<T>>, ZipEvent) {
         let this = self.project();
         match this.inner {
-            Inner::Fill(all) => {
-                let items_and_streams = ready!(Pin::new(all).poll(ctx));
-                let (res, inner) = items_and_streams
-                    .into_iter()
-                    .try_fold(
-                        (Vec::new(), FuturesUnordered::new()),
-                        |(mut items, next_items), (item, stream)| {
-                            let i = items.len();
-                            items.push(item?);
-                            next_items.push(IndexedStream::new(i, stream).into_future());
-                            Some((items, next_items))
-                        },
-                    )
-                    .map(|(items, next_items)| {
-                        (
-                            Some((this.combine)(&items)),
-                            Inner::Filled(Filled { items, next_items }),
+            Inner::Fill(all) => match Pin::new(all).poll(ctx) {
+                Poll::Ready(items_and_streams) => {
+                    let (res, inner) = items_and_streams
+                        .into_iter()
+                        .try_fold(
+                            (Vec::new(), FuturesUnordered::new()),
+                            |(mut items, next_items), (item, stream)| {
+                                match item {
+                                    Some(item) => {
+                                        let i = items.len();
+                                        items.push(item);
+                                        next_items.push(IndexWith::new(stream, i).into_future());
+                                    }
+                                    None if *this.lossy => {}
+                                    None => return None,
+                                }
+                                Some((items, next_items))
+                            },
                         )
-                    })
-                    .unwrap_or_else(|| (None, Inner::Filled(Default::default())));
-                *this.inner = inner;
-                Poll::Ready(res)
-            }
+                        .filter(|(items, _)| !*this.lossy || !items.is_empty())
+                        .map(|(items, next_items)| {
+                            (
+                                Some((this.combine)(&items)),
+                                Inner::Filled(Filled { items, next_items }),
+                            )
+                        })
+                        .unwrap_or_else(|| (None, Inner::Filled(Default::default())));
+                    let event = match (&res, &inner) {
+                        (Some(_), Inner::Filled(Filled { items, .. })) => ZipEvent::Emitted {
+                            changed: (0..items.len()).collect(),
+                        },
+                        _ => ZipEvent::Terminated,
+                    };
+                    *this.inner = inner;
+                    (Poll::Ready(res), event)
+                }
+                Poll::Pending => (Poll::Pending, ZipEvent::Filling),
+            },
             Inner::Filled(Filled { items, next_items }) => {
                 let mut yielded = Vec::new();
+                let mut changed = Vec::new();
                 loop {
                     match Pin::new(&mut *next_items).poll_next(ctx) {
                         Poll::Ready(Some((Some((i, head)), tail))) => {
                             items[i] = head;
+                            changed.push(i);
                             yielded.push(tail);
                         }
                         Poll::Ready(Some((None, _))) => {}
@@ -95,14 +193,23 @@ where
                                 .filter(|_| !yielded.is_empty())
                                 .map(|items| (this.combine)(items));
                             next_items.extend(yielded.into_iter().map(|s| s.into_future()));
-                            break Poll::Ready(res);
+                            let event = match res {
+                                Some(_) => ZipEvent::Emitted { changed },
+                                None => ZipEvent::Terminated,
+                            };
+                            break (Poll::Ready(res), event);
                         }
                         Poll::Pending => {
                             let res = Some(&*items)
                                 .filter(|_| !yielded.is_empty())
                                 .map(|items| (this.combine)(items));
                             next_items.extend(yielded.into_iter().map(|s| s.into_future()));
-                            break res.map_or(Poll::Pending, |items| Poll::Ready(Some(items)));
+                            break match res {
+                                Some(item) => {
+                                    (Poll::Ready(Some(item)), ZipEvent::Emitted { changed })
+                                }
+                                None => (Poll::Pending, ZipEvent::Pending),
+                            };
                         }
                     }
                 }
@@ -111,10 +218,80 @@ where
     }
 }
 
-impl<S, F, T> FusedStream for ZipLatestWithAll<S, F>
+/// Boxed function combining the latest items of zipped streams
+pub type BoxCombine<I, T> = Box<dyn FnMut(&[I]) -> T + Send>;
+
+/// Stream returned by
+/// [`zip_latest_with_all_swappable`](crate::stream::zip_latest_with_all_swappable)
+#[cfg(feature = "std")]
+pub type SwappableZipLatestWithAll<S, T> = ZipLatestWithAll<S, BoxCombine<<S as Stream>::Item, T>>;
+
+/// Handle to replace the function combining the latest items of a stream returned by
+/// [`zip_latest_with_all_swappable`](crate::stream::zip_latest_with_all_swappable)
+#[cfg(feature = "std")]
+pub struct CombineHandle<I, T>(Arc<Mutex<BoxCombine<I, T>>>);
+
+#[cfg(feature = "std")]
+impl<I, T> CombineHandle<I, T> {
+    pub(crate) fn new(combine: BoxCombine<I, T>) -> Self {
+        Self(Arc::new(Mutex::new(combine)))
+    }
+
+    /// Replaces the function combining the latest items
+    ///
+    /// `combine` is used from the next emitted item on. The latest items of the underlying streams
+    /// are preserved.
+    pub fn set(&self, combine: BoxCombine<I, T>) {
+        *self.0.lock().unwrap() = combine;
+    }
+
+    pub(crate) fn combine(&self, items: &[I]) -> T {
+        (self.0.lock().unwrap())(items)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, T> Clone for CombineHandle<I, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, T> Debug for CombineHandle<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CombineHandle")
+    }
+}
+
+/// Event reported to the observer of a [`ZipLatestWithAll`] stream on each poll
+///
+/// See [`zip_latest_with_all_observed`](crate::stream::zip_latest_with_all_observed).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ZipEvent {
+    /// Not all streams have produced their first item yet
+    Filling,
+    /// An item was emitted after the streams at the given indices produced new items
+    Emitted {
+        /// Indices of the streams that produced a new item, in the order they were received
+        ///
+        /// Indices are positions in the slice of latest items. When empty streams are left out,
+        /// as with [`zip_latest_with_all_lossy`](crate::stream::zip_latest_with_all_lossy), these
+        /// positions are compacted and may differ from the indices of the streams as originally
+        /// given.
+        changed: Vec<usize>,
+    },
+    /// No stream produced a new item
+    Pending,
+    /// The zipped stream ended
+    Terminated,
+}
+
+impl<S, F, O, T> FusedStream for ZipLatestWithAll<S, F, O>
 where
     S: Stream + Unpin,
     F: FnMut(&[S::Item]) -> T,
+    O: FnMut(ZipEvent),
 {
     fn is_terminated(&self) -> bool {
         match &self.inner {
@@ -133,7 +310,7 @@ impl<S: Stream + Unpin> Unpin for Inner<S> {}
 
 struct Filled<S: Stream + Unpin> {
     items: Vec<S::Item>,
-    next_items: FuturesUnordered<StreamFuture<IndexedStream<S>>>,
+    next_items: FuturesUnordered<StreamFuture<IndexWith<S>>>,
 }
 
 impl<S: Stream + Unpin> Default for Filled<S> {
@@ -145,30 +322,13 @@ impl<S: Stream + Unpin> Default for Filled<S> {
     }
 }
 
-struct IndexedStream<S> {
-    i: usize,
-    s: S,
-}
-
-impl<S> IndexedStream<S> {
-    fn new(i: usize, s: S) -> Self {
-        Self { i, s }
-    }
-}
-
-impl<S: Stream + Unpin> Stream for IndexedStream<S> {
-    type Item = (usize, S::Item);
-
-    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let x = ready!(Pin::new(&mut self.s).poll_next(ctx));
-        Poll::Ready(x.map(|x| (self.i, x)))
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::stream::{test_util::yield_on_none, zip_latest_with_all};
-    use futures::{executor::block_on, pin_mut, StreamExt};
+    use crate::stream::{
+        test_util::yield_on_none, zip_latest_with_all, zip_latest_with_all_lossy,
+        zip_latest_with_all_observed, zip_latest_with_all_pinned, ZipEvent,
+    };
+    use futures::{channel::mpsc, executor::block_on, pin_mut, stream::empty, StreamExt};
 
     #[test]
     fn it_works() {
@@ -185,4 +345,160 @@ mod tests {
         );
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn observer_receives_events() {
+        let a = yield_on_none([Some(0), None, Some(1)]);
+        pin_mut!(a);
+        let b = yield_on_none([None, Some(10), None, None, Some(11)]);
+        pin_mut!(b);
+        let mut events = Vec::new();
+        let actual = block_on(
+            zip_latest_with_all_observed(
+                [a.left_stream(), b.right_stream()],
+                |items| items.iter().sum::<i32>(),
+                |event| events.push(event),
+            )
+            .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [10, 11, 12]);
+        assert_eq!(
+            events,
+            [
+                ZipEvent::Filling,
+                ZipEvent::Emitted {
+                    changed: vec![0, 1]
+                },
+                ZipEvent::Pending,
+                ZipEvent::Emitted { changed: vec![0] },
+                ZipEvent::Emitted { changed: vec![1] },
+                ZipEvent::Terminated,
+            ],
+        );
+    }
+
+    #[test]
+    fn combine_can_be_replaced() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let sum: fn(&[i32]) -> i32 = |items| items.iter().sum();
+        let mut zipped = zip_latest_with_all([a, b], sum);
+        block_on(async {
+            a_sender.unbounded_send(1).unwrap();
+            b_sender.unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            zipped.set_combine(|items| items.iter().copied().max().unwrap());
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(10));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn combine_can_be_replaced_through_handle() {
+        use crate::stream::zip_latest_with_all_swappable;
+
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (mut zipped, handle) =
+            zip_latest_with_all_swappable([a, b], Box::new(|items| items.iter().sum()));
+        block_on(async {
+            a_sender.unbounded_send(1).unwrap();
+            b_sender.unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            handle.set(Box::new(|items| items.iter().copied().max().unwrap()));
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(10));
+        });
+    }
+
+    #[test]
+    fn streams_that_are_not_unpin_can_be_zipped() {
+        let a = yield_on_none(vec![Some(0), None, Some(1), None, None, Some(2)]);
+        let b = yield_on_none(vec![
+            None,
+            Some(10),
+            Some(11),
+            Some(12),
+            None,
+            None,
+            Some(13),
+        ]);
+        let expected = [10, 12, 13, 14, 15];
+        let actual = block_on(
+            zip_latest_with_all_pinned([a, b], |items| items.iter().sum::<i32>())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn empty_streams_are_ignored_when_lossy() {
+        let a = yield_on_none(vec![Some(0), None, Some(1)]);
+        let b = yield_on_none(vec![]);
+        let c = yield_on_none(vec![None, Some(10), None, None, Some(11)]);
+        let expected = [10, 11, 12];
+        let actual = block_on(
+            zip_latest_with_all_lossy([Box::pin(a), Box::pin(b), Box::pin(c)], |items| {
+                items.iter().sum::<i32>()
+            })
+            .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn lossy_zip_of_empty_streams_is_empty() {
+        let actual = block_on(
+            zip_latest_with_all_lossy([empty::<i32>(), empty()], |items| items.len())
+                .collect::<Vec<_>>(),
+        );
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn debug_reports_phase_and_counts() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let mut zipped = zip_latest_with_all([a, b], |items| items.to_vec());
+        assert!(format!("{zipped:?}").contains("phase: Fill,"));
+        a_sender.unbounded_send(0).unwrap();
+        b_sender.unbounded_send(1).unwrap();
+        assert_eq!(block_on(zipped.next()), Some(vec![0, 1]));
+        let debug = format!("{zipped:?}");
+        assert!(debug.contains("phase: Filled"), "{debug}");
+        assert!(debug.contains("items: 2"), "{debug}");
+        assert!(debug.contains("pending: 2"), "{debug}");
+    }
+
+    #[test]
+    fn combine_sees_items_in_index_order() {
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..4).map(|_| mpsc::unbounded()).unzip();
+        let mut zipped = zip_latest_with_all(receivers, |items: &[usize]| items.to_vec());
+        for n in 0..100 {
+            for (i, sender) in senders.iter().enumerate().rev() {
+                sender.unbounded_send(4 * n + i).unwrap();
+            }
+            let expected = (0..4).map(|i| 4 * n + i).collect::<Vec<_>>();
+            assert_eq!(block_on(zipped.next()), Some(expected));
+        }
+    }
+
+    #[test]
+    fn latest_items_are_folded_by_reference() {
+        #[derive(Debug)]
+        struct NotClone(i32);
+
+        let a = yield_on_none([Some(0), None, Some(1)]).map(NotClone);
+        pin_mut!(a);
+        let b = yield_on_none([None, Some(10), None, None, Some(11)]).map(NotClone);
+        pin_mut!(b);
+        let actual = block_on(
+            zip_latest_with_all([a.left_stream(), b.right_stream()], |items| {
+                items.iter().map(|item| item.0).sum::<i32>()
+            })
+            .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [10, 11, 12]);
+    }
 }
@@ -0,0 +1,339 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::IndexWith;
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    future::{join_all, JoinAll},
+    stream::{abortable, AbortHandle, Abortable, FusedStream, FuturesUnordered, StreamFuture},
+    Stream, StreamExt,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{ready, Context, Poll},
+};
+
+type Tracked<S> = StreamFuture<IndexWith<Abortable<S>>>;
+
+/// Stream returned by
+/// [`zip_latest_with_all_dynamic`](crate::stream::zip_latest_with_all_dynamic).
+pub struct ZipLatestWithAllDynamic<S, F>
+where
+    S: Stream + Unpin,
+{
+    fill: Option<JoinAll<Tracked<S>>>,
+    ids: Vec<usize>,
+    items: Vec<S::Item>,
+    next_items: FuturesUnordered<Tracked<S>>,
+    aborts: HashMap<usize, AbortHandle>,
+    commands: UnboundedReceiver<Command<S>>,
+    commands_done: bool,
+    terminated: bool,
+    combine: F,
+}
+
+impl<S, F, T> ZipLatestWithAllDynamic<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&[S::Item]) -> T,
+{
+    pub(crate) fn new<I>(streams: I, combine: F) -> (Self, ZipLatestWithAllHandle<S>)
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut aborts = HashMap::new();
+        let fill = join_all(streams.into_iter().enumerate().map(|(i, s)| {
+            let (s, abort) = abortable(s);
+            aborts.insert(i, abort);
+            IndexWith::new(s, i).into_future()
+        }));
+        let (sender, commands) = mpsc::unbounded();
+        let handle = ZipLatestWithAllHandle {
+            sender,
+            next_id: Arc::new(AtomicUsize::new(aborts.len())),
+        };
+        let zipped = Self {
+            fill: Some(fill),
+            ids: Vec::new(),
+            items: Vec::new(),
+            next_items: FuturesUnordered::new(),
+            aborts,
+            commands,
+            commands_done: false,
+            terminated: false,
+            combine,
+        };
+        (zipped, handle)
+    }
+
+    fn apply(&mut self, command: Command<S>) {
+        match command {
+            Command::Add(id, s) => {
+                let (s, abort) = abortable(s);
+                self.aborts.insert(id, abort);
+                self.next_items.push(IndexWith::new(s, id).into_future());
+            }
+            Command::Remove(id) => {
+                if let Some(abort) = self.aborts.remove(&id) {
+                    abort.abort();
+                }
+                if let Some(slot) = self.ids.iter().position(|&i| i == id) {
+                    self.ids.remove(slot);
+                    self.items.remove(slot);
+                }
+            }
+        }
+    }
+
+    fn update(&mut self, id: usize, item: S::Item) {
+        match self.ids.iter().position(|&i| i == id) {
+            Some(slot) => self.items[slot] = item,
+            None => {
+                self.ids.push(id);
+                self.items.push(item);
+            }
+        }
+    }
+}
+
+impl<S, F> Debug for ZipLatestWithAllDynamic<S, F>
+where
+    S: Stream + Unpin,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithAllDynamic")
+    }
+}
+
+impl<S, F> Unpin for ZipLatestWithAllDynamic<S, F> where S: Stream + Unpin {}
+
+impl<S, F, T> Stream for ZipLatestWithAllDynamic<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&[S::Item]) -> T,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+        while !this.commands_done {
+            match Pin::new(&mut this.commands).poll_next(ctx) {
+                Poll::Ready(Some(command)) => this.apply(command),
+                Poll::Ready(None) => this.commands_done = true,
+                Poll::Pending => break,
+            }
+        }
+        if let Some(fill) = &mut this.fill {
+            let items_and_streams = ready!(Pin::new(fill).poll(ctx));
+            this.fill = None;
+            for (item, stream) in items_and_streams {
+                match item {
+                    Some((id, item)) if this.aborts.contains_key(&id) => {
+                        this.update(id, item);
+                        this.next_items.push(stream.into_future());
+                    }
+                    Some(_) => {}
+                    None => {
+                        this.aborts.remove(&stream.index());
+                    }
+                }
+            }
+            if !this.items.is_empty() {
+                return Poll::Ready(Some((this.combine)(&this.items)));
+            }
+        }
+        let mut updated = false;
+        loop {
+            match Pin::new(&mut this.next_items).poll_next(ctx) {
+                Poll::Ready(Some((Some((id, head)), tail))) => {
+                    if this.aborts.contains_key(&id) {
+                        this.update(id, head);
+                        this.next_items.push(tail.into_future());
+                        updated = true;
+                    }
+                }
+                Poll::Ready(Some((None, stream))) => {
+                    this.aborts.remove(&stream.index());
+                }
+                Poll::Ready(None) if this.commands_done => {
+                    let res = Some(&*this.items)
+                        .filter(|_| updated)
+                        .map(|items| (this.combine)(items));
+                    this.terminated = res.is_none();
+                    break Poll::Ready(res);
+                }
+                Poll::Ready(None) | Poll::Pending => {
+                    break if updated {
+                        Poll::Ready(Some((this.combine)(&this.items)))
+                    } else {
+                        Poll::Pending
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl<S, F, T> FusedStream for ZipLatestWithAllDynamic<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&[S::Item]) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+/// Handle to add streams to or remove streams from a [`ZipLatestWithAllDynamic`] stream
+///
+/// Streams are identified by the index returned when they are added. Streams passed to
+/// [`zip_latest_with_all_dynamic`](crate::stream::zip_latest_with_all_dynamic) are identified by
+/// their position. The zipped stream does not end while a handle is alive.
+///
+/// A stream that ends without ever producing an item is dropped as if it had been removed, whether
+/// it was passed initially or added later. It does not end the zipped stream.
+pub struct ZipLatestWithAllHandle<S> {
+    sender: UnboundedSender<Command<S>>,
+    next_id: Arc<AtomicUsize>,
+}
+
+impl<S> ZipLatestWithAllHandle<S> {
+    /// Adds a stream to the zipped stream and returns its index
+    ///
+    /// The new stream participates in the combined items once it has produced its first item.
+    pub fn add_stream(&self, stream: S) -> usize {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let _ = self.sender.unbounded_send(Command::Add(id, stream));
+        id
+    }
+
+    /// Removes the stream with the given index from the zipped stream
+    ///
+    /// Its latest item no longer participates in the combined items. Removing all streams does not
+    /// end the zipped stream.
+    pub fn remove_stream(&self, id: usize) {
+        let _ = self.sender.unbounded_send(Command::Remove(id));
+    }
+}
+
+impl<S> Clone for ZipLatestWithAllHandle<S> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            next_id: self.next_id.clone(),
+        }
+    }
+}
+
+impl<S> Debug for ZipLatestWithAllHandle<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithAllHandle")
+    }
+}
+
+enum Command<S> {
+    Add(usize, S),
+    Remove(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::zip_latest_with_all_dynamic;
+    use futures::{channel::mpsc, executor::block_on, poll, StreamExt};
+
+    #[test]
+    fn added_stream_participates_after_first_item() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (mut zipped, handle) =
+            zip_latest_with_all_dynamic([a], |items| items.iter().sum::<i32>());
+        block_on(async {
+            a_sender.unbounded_send(1).unwrap();
+            assert_eq!(zipped.next().await, Some(1));
+            let id = handle.add_stream(b);
+            assert_eq!(id, 1);
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(2));
+            b_sender.unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(12));
+            a_sender.unbounded_send(3).unwrap();
+            assert_eq!(zipped.next().await, Some(13));
+        });
+    }
+
+    #[test]
+    fn removed_stream_stops_participating() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (mut zipped, handle) =
+            zip_latest_with_all_dynamic([a, b], |items| items.iter().sum::<i32>());
+        block_on(async {
+            a_sender.unbounded_send(1).unwrap();
+            b_sender.unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            handle.remove_stream(1);
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(2));
+            drop(a_sender);
+            drop(handle);
+            assert_eq!(zipped.next().await, None);
+        });
+    }
+
+    #[test]
+    fn remaining_items_keep_their_order_after_removal() {
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..3).map(|_| mpsc::unbounded()).unzip();
+        let (mut zipped, handle) = zip_latest_with_all_dynamic(receivers, |items| items.to_vec());
+        block_on(async {
+            for (n, sender) in senders.iter().enumerate() {
+                sender.unbounded_send(n).unwrap();
+            }
+            assert_eq!(zipped.next().await, Some(vec![0, 1, 2]));
+            handle.remove_stream(1);
+            senders[2].unbounded_send(20).unwrap();
+            assert_eq!(zipped.next().await, Some(vec![0, 20]));
+        });
+    }
+
+    #[test]
+    fn stream_removed_while_filling_does_not_participate() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (mut zipped, handle) =
+            zip_latest_with_all_dynamic([a, b], |items| items.iter().sum::<i32>());
+        block_on(async {
+            b_sender.unbounded_send(10).unwrap();
+            assert!(poll!(zipped.next()).is_pending());
+            handle.remove_stream(1);
+            a_sender.unbounded_send(1).unwrap();
+            assert_eq!(zipped.next().await, Some(1));
+        });
+    }
+
+    #[test]
+    fn empty_streams_are_dropped() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (c_sender, c) = mpsc::unbounded::<i32>();
+        let (mut zipped, handle) =
+            zip_latest_with_all_dynamic([a, b], |items| items.iter().sum::<i32>());
+        block_on(async {
+            drop(b_sender);
+            a_sender.unbounded_send(1).unwrap();
+            assert_eq!(zipped.next().await, Some(1));
+            handle.add_stream(c);
+            drop(c_sender);
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(2));
+        });
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::{drain_latest, ZipLatestWithAll};
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`zip_latest_with_all_with_refresh`](crate::stream::zip_latest_with_all_with_refresh).
+#[pin_project]
+pub struct ZipLatestWithAllRefresh<S, G, F>
+where
+    S: Stream + Unpin,
+{
+    zipped: ZipLatestWithAll<S, F>,
+    #[pin]
+    refresh: Fuse<G>,
+}
+
+impl<S, G, F, T> ZipLatestWithAllRefresh<S, G, F>
+where
+    S: Stream + Unpin,
+    G: Stream,
+    F: FnMut(&[S::Item]) -> T,
+{
+    pub(crate) fn new<I>(streams: I, refresh: G, combine: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self {
+            zipped: ZipLatestWithAll::new(streams, combine),
+            refresh: refresh.fuse(),
+        }
+    }
+}
+
+impl<S, G, F> Debug for ZipLatestWithAllRefresh<S, G, F>
+where
+    S: Stream + Unpin,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithAllRefresh")
+    }
+}
+
+impl<S, G, F, T> Stream for ZipLatestWithAllRefresh<S, G, F>
+where
+    S: Stream + Unpin,
+    G: Stream,
+    F: FnMut(&[S::Item]) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if let Poll::Ready(res) = Pin::new(&mut *this.zipped).poll_next(ctx) {
+            return Poll::Ready(res);
+        }
+        if this.zipped.items().is_empty() {
+            if drain_latest(this.refresh.as_mut(), ctx, |_| {}) {
+                ctx.waker().wake_by_ref();
+            }
+            return Poll::Pending;
+        }
+        match this.refresh.as_mut().poll_next(ctx) {
+            Poll::Ready(Some(_)) => this
+                .zipped
+                .recombine()
+                .map_or(Poll::Pending, |res| Poll::Ready(Some(res))),
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S, G, F, T> FusedStream for ZipLatestWithAllRefresh<S, G, F>
+where
+    S: Stream + Unpin,
+    G: Stream,
+    F: FnMut(&[S::Item]) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.zipped.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::zip_latest_with_all_with_refresh;
+    use futures::{
+        channel::mpsc, executor::block_on, stream::repeat, task::noop_waker_ref, StreamExt,
+    };
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn refresh_ticks_emit_latest_items() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let (b_sender, b) = mpsc::unbounded::<i32>();
+        let (tick_sender, ticks) = mpsc::unbounded();
+        let mut zipped =
+            zip_latest_with_all_with_refresh([a, b], ticks, |items| items.iter().sum::<i32>());
+        block_on(async {
+            a_sender.unbounded_send(1).unwrap();
+            b_sender.unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            tick_sender.unbounded_send(()).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            tick_sender.unbounded_send(()).unwrap();
+            assert_eq!(zipped.next().await, Some(11));
+            a_sender.unbounded_send(2).unwrap();
+            assert_eq!(zipped.next().await, Some(12));
+            drop((a_sender, b_sender));
+            assert_eq!(zipped.next().await, None);
+        });
+    }
+
+    #[test]
+    fn always_ready_refresh_does_not_block_filling() {
+        let (a_sender, a) = mpsc::unbounded::<i32>();
+        let mut zipped =
+            zip_latest_with_all_with_refresh([a], repeat(()), |items| items.iter().sum::<i32>());
+        let mut ctx = Context::from_waker(noop_waker_ref());
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Pending);
+        a_sender.unbounded_send(1).unwrap();
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(1)));
+        assert_eq!(zipped.poll_next_unpin(&mut ctx), Poll::Ready(Some(1)));
+    }
+}
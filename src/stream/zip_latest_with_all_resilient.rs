@@ -0,0 +1,203 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::IndexWith;
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug},
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{
+    stream::{FusedStream, FuturesUnordered, StreamFuture},
+    Stream, StreamExt,
+};
+
+/// Stream returned by
+/// [`zip_latest_with_all_resilient`](crate::stream::zip_latest_with_all_resilient).
+pub struct ZipLatestWithAllResilient<S, Fac, F>
+where
+    S: Stream + Unpin,
+{
+    latest: Latest<S::Item>,
+    next_items: FuturesUnordered<StreamFuture<IndexWith<S>>>,
+    factories: Vec<Fac>,
+    terminated: bool,
+    combine: F,
+}
+
+impl<S, Fac, F, T> ZipLatestWithAllResilient<S, Fac, F>
+where
+    S: Stream + Unpin,
+    Fac: FnMut() -> S,
+    F: FnMut(&[S::Item]) -> T,
+{
+    pub(crate) fn new<I>(streams: I, factories: Vec<Fac>, combine: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let next_items = streams
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| IndexWith::new(s, i).into_future())
+            .collect::<FuturesUnordered<_>>();
+        Self {
+            latest: Latest::Filling((0..next_items.len()).map(|_| None).collect()),
+            next_items,
+            factories,
+            terminated: false,
+            combine,
+        }
+    }
+}
+
+impl<S, Fac, F> Debug for ZipLatestWithAllResilient<S, Fac, F>
+where
+    S: Stream + Unpin,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithAllResilient")
+    }
+}
+
+impl<S, Fac, F> Unpin for ZipLatestWithAllResilient<S, Fac, F> where S: Stream + Unpin {}
+
+impl<S, Fac, F, T> Stream for ZipLatestWithAllResilient<S, Fac, F>
+where
+    S: Stream + Unpin,
+    Fac: FnMut() -> S,
+    F: FnMut(&[S::Item]) -> T,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+        let mut yielded = Vec::new();
+        let exhausted = loop {
+            match Pin::new(&mut this.next_items).poll_next(ctx) {
+                Poll::Ready(Some((Some((i, head)), tail))) => {
+                    this.latest.set(i, head);
+                    yielded.push(tail);
+                }
+                Poll::Ready(Some((None, stream))) => match this.factories.get_mut(stream.index()) {
+                    Some(factory) => this
+                        .next_items
+                        .push(IndexWith::new(factory(), stream.index()).into_future()),
+                    None if !this.latest.has(stream.index()) => {
+                        this.terminated = true;
+                        return Poll::Ready(None);
+                    }
+                    None => {}
+                },
+                Poll::Ready(None) => break true,
+                Poll::Pending => break false,
+            }
+        };
+        let res = this
+            .latest
+            .filled()
+            .filter(|_| !yielded.is_empty())
+            .map(|items| (this.combine)(items));
+        this.next_items
+            .extend(yielded.into_iter().map(|s| s.into_future()));
+        match res {
+            Some(item) => Poll::Ready(Some(item)),
+            None if exhausted => {
+                this.terminated = true;
+                Poll::Ready(None)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<S, Fac, F, T> FusedStream for ZipLatestWithAllResilient<S, Fac, F>
+where
+    S: Stream + Unpin,
+    Fac: FnMut() -> S,
+    F: FnMut(&[S::Item]) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+enum Latest<T> {
+    Filling(Vec<Option<T>>),
+    Filled(Vec<T>),
+}
+
+impl<T> Latest<T> {
+    fn set(&mut self, i: usize, item: T) {
+        match self {
+            Latest::Filling(items) => {
+                items[i] = Some(item);
+                if items.iter().all(Option::is_some) {
+                    *self = Latest::Filled(mem::take(items).into_iter().flatten().collect());
+                }
+            }
+            Latest::Filled(items) => items[i] = item,
+        }
+    }
+
+    fn has(&self, i: usize) -> bool {
+        match self {
+            Latest::Filling(items) => items[i].is_some(),
+            Latest::Filled(_) => true,
+        }
+    }
+
+    fn filled(&self) -> Option<&[T]> {
+        match self {
+            Latest::Filling(_) => None,
+            Latest::Filled(items) => Some(items),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::{test_util::yield_on_none, zip_latest_with_all_resilient};
+    use futures::{
+        executor::block_on,
+        stream::{iter, pending, BoxStream},
+        StreamExt,
+    };
+
+    #[test]
+    fn ended_stream_is_replaced() {
+        let mut incarnations = 0;
+        let a_factory = move || -> BoxStream<'static, i32> {
+            incarnations += 1;
+            match incarnations {
+                1 => iter([3, 4]).boxed(),
+                _ => pending().boxed(),
+            }
+        };
+        let b_factory = || -> BoxStream<'static, i32> { pending().boxed() };
+        let factories: Vec<Box<dyn FnMut() -> BoxStream<'static, i32>>> =
+            vec![Box::new(a_factory), Box::new(b_factory)];
+        let zipped = zip_latest_with_all_resilient(
+            [iter([1, 2]).boxed(), iter([10]).boxed()],
+            factories,
+            |items| items.iter().sum::<i32>(),
+        );
+        let actual = block_on(zipped.take(4).collect::<Vec<_>>());
+        assert_eq!(actual, [11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn ended_stream_without_factory_keeps_its_item_while_others_fill() {
+        let factories = Vec::<fn() -> BoxStream<'static, i32>>::new();
+        let zipped = zip_latest_with_all_resilient(
+            [iter([1]).boxed(), yield_on_none([None, Some(10)]).boxed()],
+            factories,
+            |items| items.iter().sum::<i32>(),
+        );
+        let actual = block_on(zipped.collect::<Vec<_>>());
+        assert_eq!(actual, [11]);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::IndexWith;
+use futures::{
+    stream::{FusedStream, FuturesUnordered, StreamFuture},
+    Stream, StreamExt,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Stream returned by
+/// [`zip_latest_with_all_sparse_map`](crate::stream::zip_latest_with_all_sparse_map).
+pub struct ZipLatestWithAllSparseMap<S, F>
+where
+    S: Stream + Unpin,
+{
+    items: HashMap<usize, S::Item>,
+    next_items: FuturesUnordered<StreamFuture<IndexWith<S>>>,
+    terminated: bool,
+    combine: F,
+}
+
+impl<S, F, T> ZipLatestWithAllSparseMap<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&HashMap<usize, S::Item>) -> T,
+{
+    pub(crate) fn new<I>(streams: I, combine: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self {
+            items: HashMap::new(),
+            next_items: streams
+                .into_iter()
+                .enumerate()
+                .map(|(i, s)| IndexWith::new(s, i).into_future())
+                .collect(),
+            terminated: false,
+            combine,
+        }
+    }
+}
+
+impl<S, F> Debug for ZipLatestWithAllSparseMap<S, F>
+where
+    S: Stream + Unpin,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithAllSparseMap")
+    }
+}
+
+impl<S, F> Unpin for ZipLatestWithAllSparseMap<S, F> where S: Stream + Unpin {}
+
+impl<S, F, T> Stream for ZipLatestWithAllSparseMap<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&HashMap<usize, S::Item>) -> T,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+        let mut yielded = Vec::new();
+        let done = loop {
+            match this.next_items.poll_next_unpin(ctx) {
+                Poll::Ready(Some((Some((i, head)), tail))) => {
+                    this.items.insert(i, head);
+                    yielded.push(tail);
+                }
+                Poll::Ready(Some((None, _))) => {}
+                Poll::Ready(None) => break true,
+                Poll::Pending => break false,
+            }
+        };
+        if yielded.is_empty() {
+            this.terminated = done;
+            return if done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+        this.next_items
+            .extend(yielded.into_iter().map(|s| s.into_future()));
+        Poll::Ready(Some((this.combine)(&this.items)))
+    }
+}
+
+impl<S, F, T> FusedStream for ZipLatestWithAllSparseMap<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&HashMap<usize, S::Item>) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::zip_latest_with_all_sparse_map;
+    use futures::{channel::mpsc, executor::block_on, StreamExt};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn map_grows_as_streams_produce() {
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..3).map(|_| mpsc::unbounded()).unzip();
+        let mut zipped = zip_latest_with_all_sparse_map(receivers, |items| {
+            items
+                .iter()
+                .map(|(&i, &x)| (i, x))
+                .collect::<BTreeMap<_, _>>()
+        });
+        block_on(async {
+            senders[2].unbounded_send(10).unwrap();
+            assert_eq!(zipped.next().await, Some(BTreeMap::from([(2, 10)])));
+            senders[0].unbounded_send(0).unwrap();
+            assert_eq!(zipped.next().await, Some(BTreeMap::from([(0, 0), (2, 10)])));
+            senders[2].unbounded_send(11).unwrap();
+            assert_eq!(zipped.next().await, Some(BTreeMap::from([(0, 0), (2, 11)])));
+            senders[1].unbounded_send(20).unwrap();
+            assert_eq!(
+                zipped.next().await,
+                Some(BTreeMap::from([(0, 0), (1, 20), (2, 11)]))
+            );
+            drop(senders);
+            assert_eq!(zipped.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::ZipLatest;
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`StreamTools::zip_latest_with_cloned`](crate::StreamTools::zip_latest_with_cloned).
+#[pin_project]
+pub struct ZipLatestWithCloned<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    inner: ZipLatest<A, B>,
+    combine: F,
+}
+
+impl<A, B, F, T> ZipLatestWithCloned<A, B, F>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    F: FnMut(A::Item, B::Item) -> T,
+{
+    pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
+        Self {
+            inner: ZipLatest::new(stream, other_stream),
+            combine,
+        }
+    }
+}
+
+impl<A, B, F> Clone for ZipLatestWithCloned<A, B, F>
+where
+    A: Stream + Clone,
+    A::Item: Clone,
+    B: Stream + Clone,
+    B::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            combine: self.combine.clone(),
+        }
+    }
+}
+
+impl<A, B, F> Debug for ZipLatestWithCloned<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZipLatestWithCloned")
+    }
+}
+
+impl<A, B, F, T> Stream for ZipLatestWithCloned<A, B, F>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    F: FnMut(A::Item, B::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let combine = this.combine;
+        this.inner
+            .poll_next(ctx)
+            .map(|items| items.map(|(a, b)| combine(a, b)))
+    }
+}
+
+impl<A, B, F, T> FusedStream for ZipLatestWithCloned<A, B, F>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    F: FnMut(A::Item, B::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn combine_takes_items_by_value() {
+        let a = yield_on_none([Some("a".to_owned()), None, Some("b".to_owned())]);
+        let b = yield_on_none([None, Some("0".to_owned()), None, None, Some("1".to_owned())]);
+        let expected = [["a", "0"], ["b", "0"], ["b", "1"]];
+        let actual = block_on(
+            a.zip_latest_with_cloned(b, |a, b| vec![a, b])
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::stream::{
+    drain_latest,
+    fuse::Fuse,
+    zip_latest_with::{combine_states, states_terminated, StreamState},
+};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{stream::FusedStream, Stream};
+use pin_project::pin_project;
+
+/// Stream returned by
+/// [`StreamTools::zip_latest_with_drained`](crate::StreamTools::zip_latest_with_drained).
+#[pin_project]
+#[derive(Debug)]
+pub struct ZipLatestWithDrained<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    #[pin]
+    stream: Fuse<A>,
+    #[pin]
+    other_stream: Fuse<B>,
+    state: StreamState<A::Item>,
+    other_state: StreamState<B::Item>,
+    combine: F,
+}
+
+impl<A, B, F, T> ZipLatestWithDrained<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    pub(crate) fn new(stream: A, other_stream: B, combine: F) -> Self {
+        Self {
+            stream: Fuse::new(stream),
+            other_stream: Fuse::new(other_stream),
+            state: StreamState::Nothing,
+            other_state: StreamState::Nothing,
+            combine,
+        }
+    }
+}
+
+impl<A, B, F> ZipLatestWithDrained<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+{
+    /// Returns the latest items received from both streams
+    ///
+    /// Returns `None` if either stream has not produced an item yet. This does not poll the
+    /// streams.
+    pub fn latest(&self) -> Option<(&A::Item, &B::Item)> {
+        self.state.get().zip(self.other_state.get())
+    }
+
+    /// Returns the underlying streams and combining function
+    ///
+    /// The latest items received from the streams are dropped, including items that have not been
+    /// combined yet.
+    pub fn into_inner(self) -> (A, B, F) {
+        (
+            self.stream.into_inner(),
+            self.other_stream.into_inner(),
+            self.combine,
+        )
+    }
+}
+
+impl<A, B, F, T> Stream for ZipLatestWithDrained<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let state = &mut *this.state;
+        let other_state = &mut *this.other_state;
+        let exhausted = drain_latest(this.stream.as_mut(), ctx, |x| {
+            *state = StreamState::New(x);
+        }) | drain_latest(this.other_stream.as_mut(), ctx, |x| {
+            *other_state = StreamState::New(x);
+        });
+        let res = combine_states(
+            this.state,
+            this.other_state,
+            this.stream.is_done(),
+            this.other_stream.is_done(),
+            this.combine,
+        );
+        if exhausted && res.is_pending() {
+            ctx.waker().wake_by_ref();
+        }
+        res.map(|res| res.map(|(combined, _)| combined))
+    }
+}
+
+impl<A, B, F, T> FusedStream for ZipLatestWithDrained<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        states_terminated(
+            &self.state,
+            &self.other_state,
+            self.stream.is_done(),
+            self.other_stream.is_done(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream::test_util::yield_on_none, StreamTools};
+    use futures::{
+        executor::block_on,
+        stream::{pending, repeat},
+        task::{waker, ArcWake},
+        Stream, StreamExt,
+    };
+    use std::{
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Context,
+    };
+
+    #[derive(Default)]
+    struct WakeCount(AtomicUsize);
+
+    impl ArcWake for WakeCount {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn bursts_are_coalesced() {
+        let a = yield_on_none([Some(0), Some(1), Some(2), None, Some(3), Some(4)]);
+        let b = yield_on_none([Some(10), None, None, Some(11)]);
+        let expected = [12, 14, 15];
+        let actual = block_on(
+            a.zip_latest_with_drained(b, |i, j| i + j)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn always_ready_stream_does_not_prevent_yielding() {
+        let a = repeat(1);
+        let b = yield_on_none([None, Some(10), None, Some(11)]);
+        let actual = block_on(
+            a.zip_latest_with_drained(b, |i, j| i + j)
+                .take(3)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(actual, [11, 12, 12]);
+    }
+
+    #[test]
+    fn exhausted_budget_schedules_wake_up() {
+        let mut s = pin!(repeat(1).zip_latest_with_drained(pending::<i32>(), |i, j| i + j));
+        let count = Arc::new(WakeCount::default());
+        let waker = waker(count.clone());
+        let mut ctx = Context::from_waker(&waker);
+        assert!(s.as_mut().poll_next(&mut ctx).is_pending());
+        assert_eq!(count.0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+
+#![no_std]
+
+use core::{
+    pin::pin,
+    task::{Context, Poll},
+};
+use futures::{stream, task::noop_waker_ref, Future, Stream};
+use futuristic::{future::yield_now, StreamTools};
+
+#[test]
+fn yield_now_works_without_std() {
+    let mut ctx = Context::from_waker(noop_waker_ref());
+    let mut fut = pin!(yield_now());
+    assert_eq!(fut.as_mut().poll(&mut ctx), Poll::Pending);
+    assert_eq!(fut.poll(&mut ctx), Poll::Ready(()));
+}
+
+#[test]
+fn zip_latest_works_without_std() {
+    let mut ctx = Context::from_waker(noop_waker_ref());
+    let mut zipped = pin!(stream::iter([1, 2]).zip_latest(stream::iter([10])));
+    assert_eq!(
+        zipped.as_mut().poll_next(&mut ctx),
+        Poll::Ready(Some((1, 10)))
+    );
+    assert_eq!(
+        zipped.as_mut().poll_next(&mut ctx),
+        Poll::Ready(Some((2, 10)))
+    );
+    assert_eq!(zipped.poll_next(&mut ctx), Poll::Ready(None));
+}